@@ -0,0 +1,100 @@
+use std::array::from_fn;
+
+use crate::cartesian::{Cartesian, Point, Vector};
+
+/// Axis aligned box of lattice points, `min` and `max` corners are both included
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Aabb<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+pub type Rect = Aabb<2>;
+pub type Cuboid = Aabb<3>;
+
+impl<const N: usize> Aabb<N> {
+    /// Box spanned by two opposite corners, in any order
+    pub fn new(a: Point<N>, b: Point<N>) -> Self {
+        Self { min: a.component_min(&b), max: a.component_max(&b) }
+    }
+
+    /// Smallest box containing all the points, `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Point<N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |aabb, point| aabb.extend(&point)))
+    }
+
+    /// Number of points along each axis
+    pub fn size(&self) -> Vector<N> { self.max - self.min + 1 }
+    /// Number of points in the box
+    pub fn volume(&self) -> i64 { self.size().iter().product() }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    pub fn contains_box(&self, other: &Self) -> bool { self.contains(&other.min) && self.contains(&other.max) }
+
+    pub fn intersects(&self, other: &Self) -> bool { self.intersection(other).is_some() }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (min, max) = (self.min.component_max(&other.min), self.max.component_min(&other.max));
+        (0..N).all(|i| min[i] <= max[i]).then_some(Self { min, max })
+    }
+
+    /// Smallest box containing both boxes
+    pub fn union(&self, other: &Self) -> Self {
+        Self { min: self.min.component_min(&other.min), max: self.max.component_max(&other.max) }
+    }
+
+    /// Smallest box containing both the box and `point`
+    pub fn extend(&self, point: &Point<N>) -> Self {
+        Self { min: self.min.component_min(point), max: self.max.component_max(point) }
+    }
+
+    /// Grows the box by `amount` on every side (shrinks for negative amounts), `None` if it shrinks to nothing
+    pub fn expand(&self, amount: i64) -> Option<Self> {
+        let (min, max) = (self.min - Vector([amount; N]), self.max + Vector([amount; N]));
+        (0..N).all(|i| min[i] <= max[i]).then_some(Self { min, max })
+    }
+
+    /// Every point of the box, the first axis varying fastest
+    pub fn iter_points(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        let size = self.size();
+        (0..self.volume()).map(move |mut index| {
+            self.min + Vector(from_fn(|axis| {
+                let offset = index % size[axis];
+                index /= size[axis];
+                offset
+            }))
+        })
+    }
+
+    /// Splits the box along `axis` into the parts before and from `at`
+    pub fn split(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        let mut below = *self;
+        below.max[axis] = below.max[axis].min(at - 1);
+        let mut above = *self;
+        above.min[axis] = above.min[axis].max(at);
+        (
+            (below.min[axis] <= below.max[axis]).then_some(below),
+            (above.min[axis] <= above.max[axis]).then_some(above),
+        )
+    }
+
+    /// Disjoint boxes covering the points of the box that are not in `other`
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersection(other) else { return vec![*self] };
+        let mut parts = Vec::new();
+        let mut remaining = *self;
+        for axis in 0..N {
+            let (below, rest) = remaining.split(axis, common.min[axis]);
+            let (middle, above) = rest.expect("the intersection is inside the box").split(axis, common.max[axis] + 1);
+            parts.extend(below);
+            parts.extend(above);
+            remaining = middle.expect("the intersection is inside the box");
+        }
+        parts
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::cartesian::{Point, Vector, Vector2};
+use crate::grid::Grid;
+use crate::hash_cycles;
+
+/// Computes the next generation of `grid` into `next` (which must have the same size),
+/// `rule` receives the current cell and its in bound neighbors values.
+/// Returns whether any cell changed.
+pub fn step_into<T, R>(grid: &Grid<T>, next: &mut Grid<T>, neighborhood: &[Vector2], rule: R) -> bool
+where
+    T: PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    step_rows(grid, 0, &mut next.data, neighborhood, &rule)
+}
+
+pub fn step<T, R>(grid: &Grid<T>, neighborhood: &[Vector2], rule: R) -> Grid<T>
+where
+    T: PartialEq + Clone,
+    R: Fn(&T, &[&T]) -> T,
+{
+    let mut next = grid.clone();
+    step_into(grid, &mut next, neighborhood, rule);
+    next
+}
+
+/// Same as [`step_into`] but splits the rows across the available threads
+pub fn step_parallel_into<T, R>(grid: &Grid<T>, next: &mut Grid<T>, neighborhood: &[Vector2], rule: R) -> bool
+where
+    T: PartialEq + Send + Sync,
+    R: Fn(&T, &[&T]) -> T + Sync,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_len = grid.height.div_ceil(threads).max(1) * grid.width;
+    if chunk_len == 0 { return false; }
+    let rule = &rule;
+    std::thread::scope(|scope| {
+        next.data.chunks_mut(chunk_len).enumerate()
+            .map(|(i, chunk)| scope.spawn(move || step_rows(grid, i * chunk_len, chunk, neighborhood, rule)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .any(|handle| handle.join().unwrap())
+    })
+}
+
+fn step_rows<T, R>(grid: &Grid<T>, offset: usize, next: &mut [T], neighborhood: &[Vector2], rule: &R) -> bool
+where
+    T: PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    let mut neighbors = Vec::with_capacity(neighborhood.len());
+    let mut changed = false;
+    for (i, cell) in next.iter_mut().enumerate() {
+        let index = offset + i;
+        let pos = grid.index_to_point(index);
+        neighbors.clear();
+        neighbors.extend(neighborhood.iter()
+            .map(|&direction| pos + direction)
+            .filter(|neighbor| grid.in_bound(neighbor))
+            .map(|neighbor| &grid[&neighbor]));
+        *cell = rule(&grid.data[index], &neighbors);
+        changed |= *cell != grid.data[index];
+    }
+    changed
+}
+
+/// Double buffered automaton over a [`Grid`], stepping swaps the buffers instead of reallocating
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    neighborhood: Vec<Vector2>,
+    rule: R,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: PartialEq + Clone,
+    R: Fn(&T, &[&T]) -> T,
+{
+    pub fn new(grid: Grid<T>, neighborhood: &[Vector2], rule: R) -> Self {
+        Self { next: grid.clone(), current: grid, neighborhood: neighborhood.to_vec(), rule }
+    }
+
+    pub fn grid(&self) -> &Grid<T> { &self.current }
+    pub fn into_grid(self) -> Grid<T> { self.current }
+
+    /// Returns whether any cell changed
+    pub fn step(&mut self) -> bool {
+        let changed = step_into(&self.current, &mut self.next, &self.neighborhood, &self.rule);
+        std::mem::swap(&mut self.current, &mut self.next);
+        changed
+    }
+
+    /// Steps until a steady state is reached, returns the number of steps that changed the grid
+    pub fn run_until_stable(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Runs `count` steps, skipping ahead as soon as a cycle is detected
+    pub fn run(self, count: usize) -> Self
+    where
+        T: Eq + Hash,
+    {
+        hash_cycles(self, |mut automaton| { automaton.step(); automaton }, |automaton| automaton.current.data.clone(), count)
+    }
+}
+
+/// Next generation of a sparse set of alive cells, `rule` receives whether the cell is alive and its alive neighbors count.
+/// Only alive cells and their neighbors are considered, so `rule(false, 0)` is assumed to be `false`.
+pub fn step_sparse<const N: usize>(
+    alive: &HashSet<Point<N>>,
+    neighborhood: &[Vector<N>],
+    rule: impl Fn(bool, usize) -> bool,
+) -> HashSet<Point<N>> {
+    let mut next = HashSet::with_capacity(alive.len());
+    step_sparse_into(alive, &mut next, &mut HashMap::new(), neighborhood, rule);
+    next
+}
+
+/// Same as [`step_sparse`] but reuses the `next` and `counts` allocations
+pub fn step_sparse_into<const N: usize>(
+    alive: &HashSet<Point<N>>,
+    next: &mut HashSet<Point<N>>,
+    counts: &mut HashMap<Point<N>, usize>,
+    neighborhood: &[Vector<N>],
+    rule: impl Fn(bool, usize) -> bool,
+) {
+    counts.clear();
+    for &cell in alive {
+        counts.entry(cell).or_insert(0);
+        for &direction in neighborhood {
+            *counts.entry(cell + direction).or_insert(0) += 1;
+        }
+    }
+    next.clear();
+    next.extend(counts.iter().filter(|&(cell, &count)| rule(alive.contains(cell), count)).map(|(&cell, _)| cell));
+}
+
+/// Runs `count` steps of [`step_sparse`], skipping ahead as soon as a cycle is detected
+pub fn run_sparse<const N: usize>(
+    alive: HashSet<Point<N>>,
+    neighborhood: &[Vector<N>],
+    rule: impl Fn(bool, usize) -> bool,
+    count: usize,
+) -> HashSet<Point<N>> {
+    let mut counts = HashMap::new();
+    hash_cycles(
+        alive,
+        |alive| {
+            let mut next = HashSet::with_capacity(alive.len());
+            step_sparse_into(&alive, &mut next, &mut counts, neighborhood, &rule);
+            next
+        },
+        |alive| alive.iter().copied().collect::<BTreeSet<_>>(),
+        count,
+    )
+}
@@ -0,0 +1,413 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use super::{v2, v3, ScreenSpace, Space, Vector2, Vector3};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl std::fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a valid direction", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Error of [`parse_move`], telling an unknown direction apart from a bad distance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMoveError {
+    Direction(ParseDirectionError),
+    /// The whole move along with the reason its distance is not a valid `i64`
+    Distance(String, ParseIntError),
+}
+
+impl std::fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMoveError::Direction(error) => write!(f, "{error}"),
+            ParseMoveError::Distance(s, error) => write!(f, "{s:?} does not have a valid distance: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseMoveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseMoveError::Direction(error) => Some(error),
+            ParseMoveError::Distance(_, error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseDirectionError> for ParseMoveError {
+    fn from(error: ParseDirectionError) -> Self { ParseMoveError::Direction(error) }
+}
+
+impl TryFrom<char> for Vector2 {
+    type Error = ParseDirectionError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Direction::try_from(c).map(|direction| direction.vector())
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'u' | 'N' | 'n' => Ok(Direction::Up),
+            'v' | 'D' | 'd' | 'S' | 's' => Ok(Direction::Down),
+            '<' | 'L' | 'l' | 'W' | 'w' => Ok(Direction::Left),
+            '>' | 'R' | 'r' | 'E' | 'e' => Ok(Direction::Right),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+    /// Single characters accepted by `TryFrom<char>` or words (`up`, `north`, ...), case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "up" | "north" => Ok(Direction::Up),
+            "down" | "south" => Ok(Direction::Down),
+            "left" | "west" => Ok(Direction::Left),
+            "right" | "east" => Ok(Direction::Right),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Self::try_from(c),
+                    _ => Err(ParseDirectionError(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+/// Parses comma or newline separated moves such as `R4,U3` or `R 4\nU 3`
+pub fn parse_moves(s: &str) -> Result<Vec<(Direction, i64)>, ParseMoveError> {
+    s.split([',', '\n']).filter(|m| !m.trim().is_empty()).map(parse_move).collect()
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Clockwise, starting from `Up`
+    pub const ALL: [Self; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Panicking version of `Direction::try_from`, for trusted inputs
+    pub fn from_char(c: char) -> Self {
+        Self::try_from(c).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// Screen space vector, `Up` decreases `y` (see [`Vector2::UP`])
+    pub fn vector(&self) -> Vector2 { self.vector_in::<ScreenSpace>() }
+
+    pub fn vector_in<S: Space>(&self) -> Vector2 {
+        let up = if S::Y_DOWN { -1 } else { 1 };
+        match self {
+            Direction::Up => v2(0, up),
+            Direction::Down => v2(0, -up),
+            Direction::Left => v2(-1, 0),
+            Direction::Right => v2(1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        })
+    }
+}
+
+
+/// Eight-way compass directions, `N` is up (`v2(0, -1)`) like [`Direction::Up`]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from `N`
+    pub const ALL: [Self; 8] = [Self::N, Self::NE, Self::E, Self::SE, Self::S, Self::SW, Self::W, Self::NW];
+
+    fn index(&self) -> usize { *self as usize }
+    fn turn(&self, eighths: usize) -> Self { Self::ALL[(self.index() + eighths) % 8] }
+
+    pub fn vector(&self) -> Vector2 {
+        match self {
+            Self::N => v2(0, -1),
+            Self::NE => v2(1, -1),
+            Self::E => v2(1, 0),
+            Self::SE => v2(1, 1),
+            Self::S => v2(0, 1),
+            Self::SW => v2(-1, 1),
+            Self::W => v2(-1, 0),
+            Self::NW => v2(-1, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Self { self.turn(4) }
+    pub fn turn_45_right(&self) -> Self { self.turn(1) }
+    pub fn turn_45_left(&self) -> Self { self.turn(7) }
+    pub fn turn_right(&self) -> Self { self.turn(2) }
+    pub fn turn_left(&self) -> Self { self.turn(6) }
+    pub fn is_diagonal(&self) -> bool { self.index() % 2 == 1 }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::N,
+            Direction::Right => Self::E,
+            Direction::Down => Self::S,
+            Direction::Left => Self::W,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction8 {
+    type Error = ParseDirectionError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'N' | 'n' | '^' => Ok(Self::N),
+            'E' | 'e' | '>' => Ok(Self::E),
+            'S' | 's' | 'v' => Ok(Self::S),
+            'W' | 'w' | '<' => Ok(Self::W),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = ParseDirectionError;
+    /// Compass abbreviations (`N`, `ne`, `SW`...) or words (`north`, `south-east`...), case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "n" | "north" => Ok(Self::N),
+            "ne" | "northeast" => Ok(Self::NE),
+            "e" | "east" => Ok(Self::E),
+            "se" | "southeast" => Ok(Self::SE),
+            "s" | "south" => Ok(Self::S),
+            "sw" | "southwest" => Ok(Self::SW),
+            "w" | "west" => Ok(Self::W),
+            "nw" | "northwest" => Ok(Self::NW),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// Six directions of a pointy-top hexagonal grid, `vector` gives axial `(q, r)` offsets
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum HexDirection {
+    E,
+    SE,
+    SW,
+    W,
+    NW,
+    NE,
+}
+
+impl HexDirection {
+    /// Clockwise, starting from `E`
+    pub const ALL: [Self; 6] = [Self::E, Self::SE, Self::SW, Self::W, Self::NW, Self::NE];
+
+    fn turn(&self, sixths: usize) -> Self { Self::ALL[(*self as usize + sixths) % 6] }
+
+    pub fn vector(&self) -> Vector2 {
+        match self {
+            Self::E => v2(1, 0),
+            Self::SE => v2(0, 1),
+            Self::SW => v2(-1, 1),
+            Self::W => v2(-1, 0),
+            Self::NW => v2(0, -1),
+            Self::NE => v2(1, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Self { self.turn(3) }
+    /// 60 degrees clockwise
+    pub fn turn_right(&self) -> Self { self.turn(1) }
+    /// 60 degrees counter clockwise
+    pub fn turn_left(&self) -> Self { self.turn(5) }
+}
+
+impl TryFrom<char> for HexDirection {
+    type Error = ParseDirectionError;
+    /// Only `e` and `w` are single letter hexagonal directions
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'E' | 'e' => Ok(Self::E),
+            'W' | 'w' => Ok(Self::W),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = ParseDirectionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "e" => Ok(Self::E),
+            "se" => Ok(Self::SE),
+            "sw" => Ok(Self::SW),
+            "w" => Ok(Self::W),
+            "nw" => Ok(Self::NW),
+            "ne" => Ok(Self::NE),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// The six face directions in 3D: `North`/`South` along `y` (north is `-y` like [`Direction::Up`]),
+/// `East`/`West` along `x` and `Up`/`Down` along `z`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Direction3 {
+    North,
+    East,
+    South,
+    West,
+    Up,
+    Down,
+}
+
+impl Direction3 {
+    pub const ALL: [Self; 6] = [Self::North, Self::East, Self::South, Self::West, Self::Up, Self::Down];
+
+    pub fn vector(&self) -> Vector3 {
+        match self {
+            Self::North => v3(0, -1, 0),
+            Self::East => v3(1, 0, 0),
+            Self::South => v3(0, 1, 0),
+            Self::West => v3(-1, 0, 0),
+            Self::Up => v3(0, 0, 1),
+            Self::Down => v3(0, 0, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+        }
+    }
+
+    /// Clockwise quarter turn around the vertical axis, `Up` and `Down` are unchanged
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+            vertical => *vertical,
+        }
+    }
+
+    /// Counter clockwise quarter turn around the vertical axis, `Up` and `Down` are unchanged
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+            vertical => *vertical,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction3 {
+    type Error = ParseDirectionError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'N' => Ok(Self::North),
+            'E' => Ok(Self::East),
+            'S' => Ok(Self::South),
+            'W' => Ok(Self::West),
+            'U' => Ok(Self::Up),
+            'D' => Ok(Self::Down),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction3 {
+    type Err = ParseDirectionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "north" => Ok(Self::North),
+            "east" => Ok(Self::East),
+            "south" => Ok(Self::South),
+            "west" => Ok(Self::West),
+            "up" => Ok(Self::Up),
+            "down" => Ok(Self::Down),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Self::try_from(c),
+                    _ => Err(ParseDirectionError(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a direction followed by a distance, such as `U3`, `NE12`, `e 4` or `south-west -2`
+pub fn parse_move<D: FromStr<Err = ParseDirectionError>>(s: &str) -> Result<(D, i64), ParseMoveError> {
+    let s = s.trim();
+    // a `-` only starts the distance when followed by a digit, direction names may contain one
+    let split = s.char_indices()
+        .find(|&(i, c)| c.is_ascii_digit() || c == '-' && s[i + 1..].starts_with(|c: char| c.is_ascii_digit()))
+        .map_or(s.len(), |(i, _)| i);
+    let (direction, distance) = s.split_at(split);
+    let direction = direction.trim().parse()?;
+    let distance = distance.trim().parse().map_err(|error| ParseMoveError::Distance(s.to_string(), error))?;
+    Ok((direction, distance))
+}
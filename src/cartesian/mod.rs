@@ -0,0 +1,458 @@
+//! Points and vectors over `N` dimensions.
+//!
+//! 2D helpers follow the screen convention used by [`crate::grid::Grid`]: `x` grows to the right and `y` grows
+//! downward (row index), so [`Vector2::UP`], [`Direction::Up`] and [`Cartesian2::up`] all decrease `y`,
+//! and [`Cartesian2::rotate_right`] turns clockwise on screen. The `*_in` variants take a [`Space`] marker,
+//! use [`MathSpace`] when `y` grows upward instead.
+
+use std::{
+    array::from_fn,
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign, Neg, Deref, DerefMut},
+};
+
+use crate::num::{constant::Zero, integer::{gcd, Integer}, number::{Number, SignedNumber}};
+
+mod direction;
+pub use direction::*;
+
+/// Coordinates are `i64` by default, any [`Number`] can be used instead (`Point<2, i32>`, `Vector<3, f64>`, ...)
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point<const N: usize, T = i64>(pub [T; N]);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Vector<const N: usize, T = i64>(pub [T; N]);
+
+pub type Point2<T = i64> = Point<2, T>;
+pub type Vector2<T = i64> = Vector<2, T>;
+pub type Point3<T = i64> = Point<3, T>;
+pub type Vector3<T = i64> = Vector<3, T>;
+
+/// Row major 3x3 matrix
+pub type Matrix3<T = i64> = [[T; 3]; 3];
+
+impl Vector2 {
+    pub const ORTHOGONAL: [Self; 4] = [v2(0, 1),v2(0, -1),v2(1, 0),v2(-1, 0)];
+    pub const DIAGONAL: [Self; 4] = [v2(1, 1),v2(1, -1),v2(-1, 1),v2(-1, -1)];
+    pub const ALL_DIRECTIONS: [Self; 8] = [
+        v2(0, 1),v2(0, -1),v2(1, 0),v2(-1, 0),
+        v2(1, 1),v2(1, -1),v2(-1, 1),v2(-1, -1),
+    ];
+}
+
+impl Vector3 {
+    pub const ORTHOGONAL: [Self; 6] = [v3(1, 0, 0), v3(-1, 0, 0), v3(0, 1, 0), v3(0, -1, 0), v3(0, 0, 1), v3(0, 0, -1)];
+    /// Same order as [`Vector::all_directions`]
+    pub const ALL_DIRECTIONS: [Self; 26] = {
+        let mut directions = [v3(0, 0, 0); 26];
+        let (mut code, mut i) = (0, 0);
+        while code < 27 {
+            if code != 13 {
+                directions[i] = v3(code % 3 - 1, code / 3 % 3 - 1, code / 9 - 1);
+                i += 1;
+            }
+            code += 1;
+        }
+        directions
+    };
+}
+
+impl<T: Number> Vector<3, T> {
+    pub fn cross(&self, other: &Self) -> Self {
+        let Vector([a, b, c]) = *self;
+        let Vector([x, y, z]) = *other;
+        Vector([b * z - c * y, c * x - a * z, a * y - b * x])
+    }
+}
+
+/// The 24 rotations of the cube: signed permutation matrices with a determinant of 1
+pub fn rotation_matrices() -> [Matrix3; 24] {
+    // permutations along with their parity
+    let permutations = [([0, 1, 2], 0), ([0, 2, 1], 1), ([1, 0, 2], 1), ([1, 2, 0], 0), ([2, 0, 1], 0), ([2, 1, 0], 1)];
+    let mut rotations = [[[0; 3]; 3]; 24];
+    let mut count = 0;
+    for (permutation, parity) in permutations {
+        for signs in 0..8u32 {
+            // odd permutations need an odd number of negative signs to keep a determinant of 1
+            if (signs.count_ones() + parity) % 2 == 0 {
+                let sign = |row: usize| if signs >> row & 1 == 1 { -1 } else { 1 };
+                rotations[count] = from_fn(|row| from_fn(|col| if col == permutation[row] { sign(row) } else { 0 }));
+                count += 1;
+            }
+        }
+    }
+    rotations
+}
+
+// Could also be done with derive_more:{Deref, DerefMut}
+// But done manually here to avoid additional dependencies
+impl<const N: usize, T> Deref for Point<N, T> {
+    type Target = [T; N];
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<const N: usize, T> DerefMut for Point<N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+impl<const N: usize, T> Deref for Vector<N, T> {
+    type Target = [T; N];
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<const N: usize, T> DerefMut for Vector<N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+impl<const N: usize, T: Zero + Copy> Zero for Point<N, T> { 
+    const ZERO: Self = Self([T::ZERO; N]);
+}
+impl<const N: usize, T: Zero + Copy> Default for Point<N, T> { fn default() -> Self { Self::ZERO } }
+
+impl<const N: usize, T: Zero + Copy> Zero for Vector<N, T> { 
+    const ZERO: Self = Self([T::ZERO; N]);
+}
+impl<const N: usize, T: Zero + Copy> Default for Vector<N, T> { fn default() -> Self { Self::ZERO } }
+
+impl<const N: usize, T: Debug> Debug for Point<N, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "p({})", self.0.iter().map(|x| format!("{x:?}")).collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl<const N: usize, T: Debug> Debug for Vector<N, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v({})", self.0.iter().map(|x| format!("{x:?}")).collect::<Vec<_>>().join(", "))
+    }
+}
+
+pub const fn p2(x: i64, y: i64) -> Point<2> { Point([x, y]) }
+pub const fn v2(x: i64, y: i64) -> Vector<2> { Vector([x, y]) }
+pub const fn p3(x: i64, y: i64, z: i64) -> Point<3> { Point([x, y, z]) }
+pub const fn v3(x: i64, y: i64, z: i64) -> Vector<3> { Vector([x, y, z]) }
+
+impl<const N: usize, T: Number> Point<N, T> {
+    pub fn origin() -> Self { Self::default() }
+    pub fn vector(&self) -> Vector<N, T> { Vector(**self) }
+}
+
+impl<const N: usize, T: Number> Vector<N, T> {
+    pub fn origin() -> Self { Self::default() }
+    pub fn point(&self) -> Point<N, T> { Point(**self)}
+
+    pub fn dot(&self, other: &Self) -> T { self.iter().zip(other.iter()).fold(T::ZERO, |acc, (&a, &b)| acc + a * b) }
+
+    /// vector is a non null multiple of a basis vector (single axis)
+    pub fn is_aligned(self) -> bool { self.iter().filter(|&&x| x != T::ZERO).count() == 1 }
+    pub fn square_magnitude(self) -> T { self.dot(&self) }
+}
+
+impl<const N: usize, T: SignedNumber> Vector<N, T> {
+    /// Unit vector along the single axis of an aligned vector, `None` otherwise
+    pub fn try_normalize(self) -> Option<Self> {
+        self.is_aligned().then(|| self / self.manhattan_dist())
+    }
+
+    /// Panics if the vector is not aligned, see [`Vector::try_normalize`]
+    pub fn normalize(self) -> Self {
+        self.try_normalize().unwrap_or_else(|| panic!("{self:?} is not aligned with an axis"))
+    }
+
+    /// All the `3^N - 1` offsets with components in `-1..=1`, excluding the null vector
+    pub fn all_directions() -> Vec<Self> {
+        (0..3usize.pow(N as u32))
+            .map(|mut code| Self(from_fn(|_| {
+                let component = [-T::ONE, T::ZERO, T::ONE][code % 3];
+                code /= 3;
+                component
+            })))
+            .filter(|v| *v != Self::default())
+            .collect()
+    }
+}
+
+impl<const N: usize, T: SignedNumber + Integer> Vector<N, T> {
+    /// Smallest vector with the same direction, dividing by the gcd of the components (null vector stays null)
+    pub fn reduce(self) -> Self {
+        let divisor = self.iter().fold(T::ZERO, |acc, &x| gcd(acc, x));
+        if divisor == T::ZERO { self } else { self / divisor }
+    }
+}
+
+impl<const N: usize, T: SignedNumber> Point<N, T> {
+    /// Unit step (components in `-1..=1`) moving toward `target`
+    pub fn direction_to(&self, target: &Self) -> Vector<N, T> { (*target - *self).signum() }
+}
+
+/// Screen space directions, see the [module documentation](self)
+impl Vector2 {
+    pub const UP: Vector2 = Vector([0, -1]);
+    pub const DOWN: Vector2 = Vector([0, 1]);
+    pub const LEFT: Vector2 = Vector([-1, 0]);
+    pub const RIGHT: Vector2 = Vector([1, 0]);
+}
+
+impl<T: Number> Vector<2, T> {
+    pub fn cross(&self, other: &Self) -> T {
+        let Vector([a, b]) = *self;
+        let Vector([x, y]) = *other;
+        a * y - b * x
+    }
+}
+
+impl<const N: usize, T: Number> Sub for Point<N, T> {
+    type Output = Vector<N, T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector(from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T: Number> Add for Vector<N, T> {
+    type Output = Vector<N, T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T: Number> Sub for Vector<N, T> {
+    type Output = Vector<N, T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector(from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T: Number> AddAssign for Vector<N, T> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<const N: usize, T: Number> SubAssign for Vector<N, T> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<const N: usize, T: SignedNumber> Neg for Vector<N, T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self(self.0.map(|x| -x)) }
+}
+
+impl<const N: usize, T: Number> Sub<Vector<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<N, T>) -> Self::Output {
+        Self(from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T: Number> Add<Vector<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector<N, T>) -> Self::Output {
+        Self(from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T: Number> Add<Point<N, T>> for Vector<N, T> {
+    type Output = Point<N, T>;
+    fn add(self, rhs: Point<N, T>) -> Self::Output { Add::add(rhs, self) }
+}
+
+impl<const N: usize, T: Number> Mul<T> for Vector<N, T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output { Self(self.0.map(|x| x * rhs)) }
+}
+
+impl<const N: usize, T: Number> MulAssign<T> for Vector<N, T> {
+    fn mul_assign(&mut self, rhs: T) { *self = Mul::mul(*self, rhs) }
+}
+
+impl<const N: usize, T: Number> Div<T> for Vector<N, T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output { Self(self.0.map(|x| x / rhs)) }
+}
+
+// TODO: macro assignOperator from operator
+impl<const N: usize, T: Number> DivAssign<T> for Vector<N, T> {
+    fn div_assign(&mut self, rhs: T) { *self = Div::div(*self, rhs) }
+}
+
+impl<const N: usize, T: Number> Add<T> for Vector<N, T> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output { Self(self.0.map(|x| x + rhs)) }
+}
+
+impl<const N: usize, T: Number> AddAssign<T> for Vector<N, T> {
+    fn add_assign(&mut self, rhs: T) { *self = *self + rhs; }
+}
+
+// Component-wise products
+impl<const N: usize, T: Number> Mul for Vector<N, T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output { Self(from_fn(|i| self.0[i] * rhs.0[i])) }
+}
+
+impl<const N: usize, T: Number> MulAssign for Vector<N, T> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<const N: usize, T: Number> Div for Vector<N, T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output { Self(from_fn(|i| self.0[i] / rhs.0[i])) }
+}
+
+impl<const N: usize, T: Number> DivAssign for Vector<N, T> {
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<const N: usize, T: Number> Sub<T> for Vector<N, T> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output { Self(self.0.map(|x| x - rhs)) }
+}
+
+// Scalar on the left hand side, can't be generic over the scalar type because of the orphan rule
+macro_rules! commutative_scalar_ops {
+    ($($t:ty)*) => ($(
+        impl<const N: usize> Mul<Vector<N, $t>> for $t {
+            type Output = Vector<N, $t>;
+            fn mul(self, rhs: Vector<N, $t>) -> Self::Output { Mul::mul(rhs, self) }
+        }
+
+        impl<const N: usize> Add<Vector<N, $t>> for $t {
+            type Output = Vector<N, $t>;
+            fn add(self, rhs: Vector<N, $t>) -> Self::Output { Add::add(rhs, self) }
+        }
+    )*)
+}
+
+commutative_scalar_ops!(u8 u16 u32 u64 u128 usize i16 i32 i64 i128 f32 f64);
+
+// Vector <-> Point
+impl<const N: usize, T: Number> AddAssign<Vector<N, T>> for Point<N, T> {
+    fn add_assign(&mut self, rhs: Vector<N, T>) { *self = *self + rhs; }
+}
+
+impl<const N: usize, T: Number> SubAssign<Vector<N, T>> for Point<N, T> {
+    fn sub_assign(&mut self, rhs: Vector<N, T>) { *self = *self - rhs; }
+}
+
+pub trait Cartesian<const N: usize, T: Number = i64>:
+    Sized + Default + Clone + Copy + Deref<Target = [T; N]> + DerefMut<Target = [T; N]> + PartialEq
+{
+    fn new(x: [T; N]) -> Self;
+
+    fn manhattan_dist(&self) -> T where T: SignedNumber { self.iter().fold(T::ZERO, |acc, x| acc + x.abs()) }
+    /// Largest absolute component
+    fn chebyshev_dist(&self) -> T where T: SignedNumber {
+        self.iter().fold(T::ZERO, |acc, x| if x.abs() > acc { x.abs() } else { acc })
+    }
+    fn euclidean_dist(&self) -> f64 { self.iter().map(|x| x.to_f64() * x.to_f64()).sum::<f64>().sqrt() }
+
+    fn manhattan_dist_to(&self, other: &Self) -> T where T: SignedNumber { self.component_sub(other).manhattan_dist() }
+    fn chebyshev_dist_to(&self, other: &Self) -> T where T: SignedNumber { self.component_sub(other).chebyshev_dist() }
+    fn euclidean_dist_to(&self, other: &Self) -> f64 { self.component_sub(other).euclidean_dist() }
+
+    fn component_sub(&self, other: &Self) -> Self { Self::new(from_fn(|i| self[i] - other[i])) }
+    fn component_min(&self, other: &Self) -> Self { Self::new(from_fn(|i| if other[i] < self[i] { other[i] } else { self[i] })) }
+    fn component_max(&self, other: &Self) -> Self { Self::new(from_fn(|i| if other[i] > self[i] { other[i] } else { self[i] })) }
+    /// Clamps each component between the ones of `min` and `max`
+    fn component_clamp(&self, min: &Self, max: &Self) -> Self { self.component_max(min).component_min(max) }
+
+    fn abs(&self) -> Self where T: SignedNumber { Self::new(self.map(|x| x.abs())) }
+    fn signum(&self) -> Self where T: SignedNumber { Self::new(self.map(|x| x.signum())) }
+}
+
+pub trait Cartesian2<T: Number = i64>: Cartesian<2, T> {
+    #[inline]
+    fn x(&self) -> T { self[0] }
+    #[inline]
+    fn y(&self) -> T { self[1] }
+
+    #[inline]
+    fn x_ref(&self) -> &T { &self[0] }
+    #[inline]
+    fn y_ref(&self) -> &T { &self[1] }
+
+    #[inline]
+    fn x_ref_mut(&mut self) -> &mut T { &mut self[0] }
+    #[inline]
+    fn y_ref_mut(&mut self) -> &mut T { &mut self[1] }
+
+    fn up(&self, n: T) -> Self { self.up_in::<ScreenSpace>(n) }
+    fn down(&self, n: T) -> Self { self.down_in::<ScreenSpace>(n) }
+    fn left(&self, n: T) -> Self { Self::new([self.x() - n, self.y()]) }
+    fn right(&self, n: T) -> Self { Self::new([self.x() + n, self.y()]) }
+
+    /// Clockwise quarter turn (around the origin for points)
+    fn rotate_right(&self) -> Self where T: SignedNumber { self.rotate_right_in::<ScreenSpace>() }
+    /// Counter clockwise quarter turn (around the origin for points)
+    fn rotate_left(&self) -> Self where T: SignedNumber { self.rotate_left_in::<ScreenSpace>() }
+
+    fn up_in<S: Space>(&self, n: T) -> Self {
+        Self::new([self.x(), if S::Y_DOWN { self.y() - n } else { self.y() + n }])
+    }
+    fn down_in<S: Space>(&self, n: T) -> Self {
+        Self::new([self.x(), if S::Y_DOWN { self.y() + n } else { self.y() - n }])
+    }
+    fn rotate_right_in<S: Space>(&self) -> Self where T: SignedNumber {
+        if S::Y_DOWN { Self::new([-self.y(), self.x()]) } else { Self::new([self.y(), -self.x()]) }
+    }
+    fn rotate_left_in<S: Space>(&self) -> Self where T: SignedNumber {
+        if S::Y_DOWN { Self::new([self.y(), -self.x()]) } else { Self::new([-self.y(), self.x()]) }
+    }
+}
+
+/// Orientation of the `y` axis, see the [module documentation](self)
+pub trait Space {
+    /// Whether `y` grows downward
+    const Y_DOWN: bool;
+}
+
+/// `y` grows downward, like grid rows. This is the default convention of the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenSpace;
+
+/// `y` grows upward, like the usual mathematical axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MathSpace;
+
+impl Space for ScreenSpace { const Y_DOWN: bool = true; }
+impl Space for MathSpace { const Y_DOWN: bool = false; }
+
+// Automatically implement Cartesian2 for any type that implements Cartesian<2>
+impl<T: Number, C: Cartesian<2, T>> Cartesian2<T> for C {}
+
+pub trait Cartesian3<T: Number = i64>: Cartesian<3, T> {
+    #[inline]
+    fn x(&self) -> T { self[0] }
+    #[inline]
+    fn y(&self) -> T { self[1] }
+    #[inline]
+    fn z(&self) -> T { self[2] }
+
+    #[inline]
+    fn x_ref(&self) -> &T { &self[0] }
+    #[inline]
+    fn y_ref(&self) -> &T { &self[1] }
+    #[inline]
+    fn z_ref(&self) -> &T { &self[2] }
+
+    #[inline]
+    fn x_ref_mut(&mut self) -> &mut T { &mut self[0] }
+    #[inline]
+    fn y_ref_mut(&mut self) -> &mut T { &mut self[1] }
+    #[inline]
+    fn z_ref_mut(&mut self) -> &mut T { &mut self[2] }
+
+    /// Rotation around the origin, see [`rotation_matrices`]
+    fn rotate(&self, matrix: &Matrix3<T>) -> Self {
+        Self::new(from_fn(|row| (0..3).fold(T::ZERO, |acc, col| acc + matrix[row][col] * self[col])))
+    }
+}
+
+impl<T: Number, C: Cartesian<3, T>> Cartesian3<T> for C {}
+
+impl<const N: usize, T: Number> Cartesian<N, T> for Point<N, T> {
+    fn new(x: [T; N]) -> Self { Self(x) }
+}
+
+impl<const N: usize, T: Number> Cartesian<N, T> for Vector<N, T> {
+    fn new(x: [T; N]) -> Self { Self(x) }
+}
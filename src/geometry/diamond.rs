@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+use crate::aabb::Rect;
+use crate::cartesian::{p2, Cartesian, Cartesian2, Point2};
+use crate::range_set::RangeSet;
+
+/// Ball of the manhattan distance: every point within `radius` of `center`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Diamond {
+    pub center: Point2,
+    pub radius: i64,
+}
+
+impl Diamond {
+    pub fn new(center: Point2, radius: i64) -> Self { Self { center, radius } }
+
+    /// Smallest diamond around `center` reaching `point`, such as a sensor and its closest beacon
+    pub fn reaching(center: Point2, point: &Point2) -> Self { Self::new(center, center.manhattan_dist_to(point)) }
+
+    pub fn contains(&self, point: &Point2) -> bool { self.center.manhattan_dist_to(point) <= self.radius }
+
+    /// Points at exactly `radius` from the center, clockwise on screen starting from the top.
+    /// The candidates just outside the diamond are the boundary of `radius + 1`
+    pub fn boundary(&self) -> impl Iterator<Item = Point2> {
+        let (Diamond { center, radius }, x, y) = (*self, self.center.x(), self.center.y());
+        let sides = (0..radius).flat_map(move |i| [
+            p2(x + i, y - radius + i),
+            p2(x + radius - i, y + i),
+            p2(x - i, y + radius - i),
+            p2(x - radius + i, y - i),
+        ]);
+        (radius == 0).then_some(center).into_iter().chain(sides)
+    }
+
+    /// Covered columns of row `y`
+    pub fn row(&self, y: i64) -> Option<Range<i64>> {
+        let half_width = self.radius - (y - self.center.y()).abs();
+        (half_width >= 0).then(|| self.center.x() - half_width..self.center.x() + half_width + 1)
+    }
+
+    /// The diamond becomes this square once rotated with [`to_chebyshev`], only the points with an even
+    /// `u + v` come from the original lattice. `None` for a negative radius
+    pub fn to_chebyshev(&self) -> Option<Rect> {
+        let center = to_chebyshev(&self.center);
+        Rect::new(center, center).expand(self.radius)
+    }
+}
+
+/// Rotation by 45 degrees `(x, y) -> (x + y, x - y)`, turning manhattan distances into chebyshev distances
+pub fn to_chebyshev(point: &Point2) -> Point2 { p2(point.x() + point.y(), point.x() - point.y()) }
+
+/// Inverse of [`to_chebyshev`], `None` when the point has no integer preimage
+pub fn from_chebyshev(point: &Point2) -> Option<Point2> {
+    let (u, v) = (point.x(), point.y());
+    ((u + v) % 2 == 0).then(|| p2((u + v) / 2, (u - v) / 2))
+}
+
+/// Columns of row `y` covered by at least one diamond
+pub fn row_coverage<'a>(diamonds: impl IntoIterator<Item = &'a Diamond>, y: i64) -> RangeSet<i64> {
+    diamonds.into_iter().filter_map(|diamond| diamond.row(y)).collect()
+}
@@ -0,0 +1,179 @@
+use std::array::from_fn;
+
+use crate::cartesian::{Point, Vector};
+use crate::num::rational::Rational;
+
+/// Exact coordinate of an intersection. Only second degree products of the coordinates are needed,
+/// `i128` keeps every computation exact for coordinates up to about `2^40`
+pub type Fraction = Rational<i128>;
+
+/// Closed segment between two points
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Segment<const N: usize> {
+    pub from: Point<N>,
+    pub to: Point<N>,
+}
+
+/// Half line `origin + t * direction` for `t >= 0`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Ray<const N: usize> {
+    pub origin: Point<N>,
+    pub direction: Vector<N>,
+}
+
+/// Infinite line `origin + t * direction`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Line<const N: usize> {
+    pub origin: Point<N>,
+    pub direction: Vector<N>,
+}
+
+/// Shared part of two linear objects, colinear objects can overlap on more than a point
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Intersection<const N: usize> {
+    None,
+    Point(Point<N, Fraction>),
+    Segment(Segment<N>),
+    Ray(Ray<N>),
+    Line(Line<N>),
+}
+
+impl<const N: usize> Segment<N> {
+    pub fn new(from: Point<N>, to: Point<N>) -> Self { Self { from, to } }
+    pub fn intersection(&self, other: &Self) -> Intersection<N> { intersect(&self.linear(), &other.linear()) }
+    fn linear(&self) -> Linear<N> { Linear { origin: self.from, direction: self.to - self.from, kind: Kind::Segment } }
+}
+
+impl<const N: usize> Ray<N> {
+    pub fn new(origin: Point<N>, direction: Vector<N>) -> Self { Self { origin, direction } }
+    pub fn intersection(&self, other: &Self) -> Intersection<N> { intersect(&self.linear(), &other.linear()) }
+    fn linear(&self) -> Linear<N> { Linear { origin: self.origin, direction: self.direction, kind: Kind::Ray } }
+}
+
+impl<const N: usize> Line<N> {
+    pub fn new(origin: Point<N>, direction: Vector<N>) -> Self { Self { origin, direction } }
+    pub fn intersection(&self, other: &Self) -> Intersection<N> { intersect(&self.linear(), &other.linear()) }
+    fn linear(&self) -> Linear<N> { Linear { origin: self.origin, direction: self.direction, kind: Kind::Line } }
+}
+
+impl<const N: usize> Intersection<N> {
+    pub fn is_none(&self) -> bool { matches!(self, Intersection::None) }
+
+    /// The single intersection point, `None` when there is no intersection or an overlap
+    pub fn point(&self) -> Option<Point<N, Fraction>> {
+        match self {
+            Intersection::Point(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind { Segment, Ray, Line }
+
+/// `origin + t * direction` where `t` is in `[0, 1]`, `[0, inf)` or unbounded depending on the kind
+struct Linear<const N: usize> {
+    origin: Point<N>,
+    direction: Vector<N>,
+    kind: Kind,
+}
+
+impl<const N: usize> Linear<N> {
+    fn contains_parameter(&self, t: Fraction) -> bool {
+        match self.kind {
+            Kind::Segment => Fraction::from(0) <= t && t <= Fraction::from(1),
+            Kind::Ray => Fraction::from(0) <= t,
+            Kind::Line => true,
+        }
+    }
+
+    fn at(&self, t: Fraction) -> Point<N, Fraction> {
+        Point(from_fn(|i| Fraction::from(self.origin[i] as i128) + Fraction::from(self.direction[i] as i128) * t))
+    }
+}
+
+fn wide<const N: usize>(vector: Vector<N>) -> Vector<N, i128> { Vector(vector.0.map(i128::from)) }
+fn exact<const N: usize>(point: Point<N>) -> Point<N, Fraction> { Point(point.0.map(|x| Fraction::from(x as i128))) }
+
+/// [`Vector2::cross`](crate::cartesian::Vector2) of the projections on the `(i, j)` plane
+fn cross_in<const N: usize>(a: &Vector<N, i128>, b: &Vector<N, i128>, (i, j): (usize, usize)) -> i128 {
+    Vector([a[i], a[j]]).cross(&Vector([b[i], b[j]]))
+}
+
+fn planes<const N: usize>() -> impl Iterator<Item = (usize, usize)> { (0..N).flat_map(|i| (i + 1..N).map(move |j| (i, j))) }
+
+/// Parallel vectors have a null cross product on every plane
+fn parallel<const N: usize>(a: &Vector<N, i128>, b: &Vector<N, i128>) -> bool { planes::<N>().all(|plane| cross_in(a, b, plane) == 0) }
+
+fn intersect<const N: usize>(a: &Linear<N>, b: &Linear<N>) -> Intersection<N> {
+    let (d, e, w) = (wide(a.direction), wide(b.direction), wide(b.origin.vector()) - wide(a.origin.vector()));
+    if d == Vector::default() {
+        return point_on(&a.origin, b);
+    }
+    if e == Vector::default() {
+        return point_on(&b.origin, a);
+    }
+    // solve `t d - u e = w` on a plane where the directions are not parallel
+    let Some(plane) = planes::<N>().find(|&plane| cross_in(&d, &e, plane) != 0) else {
+        return if parallel(&w, &d) { overlap(a, b) } else { Intersection::None };
+    };
+    let denominator = cross_in(&d, &e, plane);
+    let t = Fraction::new(cross_in(&w, &e, plane), denominator);
+    let u = Fraction::new(cross_in(&w, &d, plane), denominator);
+    let point = a.at(t);
+    // above two dimensions, the other coordinates only match if the lines are not skew
+    if a.contains_parameter(t) && b.contains_parameter(u) && point == b.at(u) {
+        Intersection::Point(point)
+    } else {
+        Intersection::None
+    }
+}
+
+/// Intersection of a single point with a linear object of non null direction (or another point)
+fn point_on<const N: usize>(point: &Point<N>, linear: &Linear<N>) -> Intersection<N> {
+    let (e, w) = (wide(linear.direction), wide(*point - linear.origin));
+    let on = if e == Vector::default() {
+        w == Vector::default()
+    } else {
+        parallel(&w, &e) && linear.contains_parameter(Fraction::new(w.dot(&e), e.dot(&e)))
+    };
+    if on { Intersection::Point(exact(*point)) } else { Intersection::None }
+}
+
+/// Colinear objects, the bounds are compared through their projection on `a.direction`
+fn overlap<const N: usize>(a: &Linear<N>, b: &Linear<N>) -> Intersection<N> {
+    let d = wide(a.direction);
+    let key = |point: Point<N>| wide(point - a.origin).dot(&d);
+    let bounds = |linear: &Linear<N>| {
+        let start = Some((key(linear.origin), linear.origin));
+        let end = linear.origin + linear.direction;
+        let forward = key(end) > key(linear.origin);
+        match (linear.kind, forward) {
+            (Kind::Segment, true) => (start, Some((key(end), end))),
+            (Kind::Segment, false) => (Some((key(end), end)), start),
+            (Kind::Ray, true) => (start, None),
+            (Kind::Ray, false) => (None, start),
+            (Kind::Line, _) => (None, None),
+        }
+    };
+    let ((a_low, a_high), (b_low, b_high)) = (bounds(a), bounds(b));
+    // `None` is unbounded: lowest for the lower bounds and highest for the upper bounds
+    let low = a_low.max(b_low);
+    let high = match (a_high, b_high) {
+        (Some(x), Some(y)) => Some(x.min(y)),
+        (x, y) => x.or(y),
+    };
+    match (low, high) {
+        (Some((low, from)), Some((high, to))) => match low.cmp(&high) {
+            std::cmp::Ordering::Greater => Intersection::None,
+            std::cmp::Ordering::Equal => Intersection::Point(exact(from)),
+            std::cmp::Ordering::Less => Intersection::Segment(Segment::new(from, to)),
+        },
+        (Some((_, origin)), None) | (None, Some((_, origin))) => {
+            // keep the direction of the ray the overlap starts from
+            let direction = if b.kind == Kind::Ray && origin == b.origin || a.kind != Kind::Ray { b.direction } else { a.direction };
+            Intersection::Ray(Ray::new(origin, direction))
+        }
+        (None, None) => Intersection::Line(Line::new(a.origin, a.direction)),
+    }
+}
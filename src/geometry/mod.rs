@@ -0,0 +1,3 @@
+pub mod diamond;
+pub mod line;
+pub mod polygon;
@@ -0,0 +1,121 @@
+use crate::cartesian::{Cartesian, Cartesian2, Direction, Point2};
+use crate::num::integer::{gcd, Integer};
+
+/// Simple polygon with integer vertices, the closing edge from the last vertex to the first is implicit
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct Polygon {
+    pub vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self { Self { vertices } }
+
+    /// Follows the moves from `start`, the final move is expected to lead back to `start`
+    pub fn from_moves(start: Point2, moves: &[(Direction, i64)]) -> Self {
+        let mut vertices = vec![start];
+        for (direction, length) in moves {
+            vertices.push(*vertices.last().unwrap() + direction.vector() * *length);
+        }
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+        Self::new(vertices)
+    }
+
+    /// Smallest convex polygon containing every point (Andrew's monotone chain), without colinear vertices.
+    /// The vertices go clockwise on screen, so the signed area is positive
+    pub fn convex_hull(points: &[Point2]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_unstable();
+        points.dedup();
+        if points.len() < 3 {
+            return Self::new(points);
+        }
+        let chain = |points: &mut dyn Iterator<Item = &Point2>| {
+            let mut chain: Vec<Point2> = Vec::new();
+            for &point in points {
+                while chain.len() >= 2 && (chain[chain.len() - 1] - chain[chain.len() - 2]).cross(&(point - chain[chain.len() - 2])) <= 0 {
+                    chain.pop();
+                }
+                chain.push(point);
+            }
+            // the last point starts the other chain
+            chain.pop();
+            chain
+        };
+        let mut hull = chain(&mut points.iter());
+        hull.extend(chain(&mut points.iter().rev()));
+        Self::new(hull)
+    }
+
+    pub fn len(&self) -> usize { self.vertices.len() }
+    pub fn is_empty(&self) -> bool { self.vertices.is_empty() }
+
+    /// Iterates over every edge, including the closing one
+    pub fn edges(&self) -> impl Iterator<Item = (&Point2, &Point2)> {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// Twice the signed area, positive when the vertices go clockwise on screen (y pointing down)
+    pub fn signed_double_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.vector().cross(&b.vector())).sum()
+    }
+
+    /// Twice the area, always an integer for lattice polygons
+    pub fn double_area(&self) -> i64 { self.signed_double_area().abs() }
+
+    /// Area rounded down, see [`Polygon::double_area`] for the exact value
+    pub fn area(&self) -> i64 { self.double_area() / 2 }
+
+    pub fn perimeter(&self) -> f64 { self.edges().map(|(a, b)| a.euclidean_dist_to(b)).sum() }
+
+    /// Number of lattice points lying on the edges
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|(a, b)| { let d = *b - *a; gcd(d.x(), d.y()) }).sum()
+    }
+
+    /// Number of lattice points strictly inside, using Pick's theorem
+    pub fn interior_points(&self) -> i64 { pick_interior_points(self.double_area(), self.boundary_points()) }
+
+    /// Number of lattice points inside or on the boundary, i.e. the cells covered when the edges are dug as a trench
+    pub fn lattice_points(&self) -> i64 { self.interior_points() + self.boundary_points() }
+
+    pub fn on_boundary(&self, pos: &Point2) -> bool {
+        self.edges().any(|(a, b)| {
+            (*b - *a).cross(&(*pos - *a)) == 0
+                && a.x().min(b.x()) <= pos.x() && pos.x() <= a.x().max(b.x())
+                && a.y().min(b.y()) <= pos.y() && pos.y() <= a.y().max(b.y())
+        })
+    }
+
+    /// Whether `pos` is inside the polygon or on its boundary
+    pub fn contains(&self, pos: &Point2) -> bool { self.on_boundary(pos) || self.winding_number(pos) != 0 }
+
+    /// Whether `pos` is inside the polygon and not on its boundary
+    pub fn strictly_contains(&self, pos: &Point2) -> bool { !self.on_boundary(pos) && self.winding_number(pos) != 0 }
+
+    fn winding_number(&self, pos: &Point2) -> i64 {
+        self.edges().map(|(a, b)| {
+            let side = (*b - *a).cross(&(*pos - *a)).signum();
+            match (a.y() <= pos.y(), b.y() <= pos.y()) {
+                (true, false) if side > 0 => 1,
+                (false, true) if side < 0 => -1,
+                _ => 0,
+            }
+        }).sum()
+    }
+}
+
+/// Pick's theorem `A = i + b / 2 - 1` solved for the interior points `i`, taking twice the area to stay exact
+pub fn pick_interior_points<T>(double_area: T, boundary_points: T) -> T
+where T: Integer {
+    (double_area - boundary_points + T::TWO) / T::TWO
+}
+
+impl From<Vec<Point2>> for Polygon {
+    fn from(vertices: Vec<Point2>) -> Self { Self::new(vertices) }
+}
+
+impl FromIterator<Point2> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point2>>(iter: I) -> Self { Self::new(iter.into_iter().collect()) }
+}
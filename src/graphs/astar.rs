@@ -4,7 +4,7 @@ use std::hash::Hash;
 
 use crate::num::constant::Zero;
 
-use super::build_path;
+use super::{build_path, ParentMap};
 
 pub fn astar<Node, Cost, FN, IN, FS, FH>(
     starts: impl IntoIterator<Item = Node>,
@@ -34,7 +34,7 @@ fn run_astar<Node, Cost, FN, IN, FS, FH>(
     successors: &mut FN,
     heuristic: &mut FH,
     stop: &mut FS,
-) -> (HashMap<Node, (Option<Node>, Cost)>, Option<Node>)
+) -> (ParentMap<Node, Cost>, Option<Node>)
 where
     Node: Eq + Hash + Clone,
     Cost: Zero + Ord + Copy + std::ops::Add<Cost, Output = Cost>,
@@ -44,7 +44,7 @@ where
     FH: FnMut(&Node) -> Cost,
 {
     let mut to_visit = BinaryHeap::new();
-    let mut parent: HashMap<Node, (Option<Node>, Cost)> = HashMap::default();
+    let mut parent: ParentMap<Node, Cost> = HashMap::default();
     
     for start in starts {
         to_visit.push(SmallestHolder{
@@ -68,7 +68,7 @@ where
 
             let is_empty_or_less_costly = parent
                     .get(&neighbor)
-                    .map_or(true, |&(_, current_cost)| new_cost < current_cost);
+                    .is_none_or(|&(_, current_cost)| new_cost < current_cost);
 
             // If we already have a cheaper path to `node`, do nothing.
             if is_empty_or_less_costly {
@@ -4,7 +4,7 @@ use std::hash::Hash;
 
 use crate::num::constant::Zero;
 
-use super::build_path;
+use super::{build_path, ParentMap};
 
 pub fn dijkstra<Node, Cost, FN, IN, FS>(
     starts: impl IntoIterator<Item = Node>,
@@ -31,7 +31,7 @@ fn run_dijkstra<Node, Cost, FN, IN, FS>(
     starts: impl IntoIterator<Item = Node>,
     successors: &mut FN,
     stop: &mut FS,
-) -> (HashMap<Node, (Option<Node>, Cost)>, Option<Node>)
+) -> (ParentMap<Node, Cost>, Option<Node>)
 where
     Node: Eq + Hash + Clone,
     Cost: Zero + Ord + Copy + std::ops::Add<Cost, Output = Cost>,
@@ -40,7 +40,7 @@ where
     FS: FnMut(&Node) -> bool,
 {
     let mut to_visit = BinaryHeap::new();
-    let mut parent: HashMap<Node, (Option<Node>, Cost)> = HashMap::default();
+    let mut parent: ParentMap<Node, Cost> = HashMap::default();
     
     for start in starts {
         to_visit.push(SmallestHolder{cost: Cost::ZERO, data: start.clone()});
@@ -60,7 +60,7 @@ where
 
             let is_empty_or_less_costly = parent
                     .get(&neighbor)
-                    .map_or(true, |&(_, current_cost)| new_cost < current_cost);
+                    .is_none_or(|&(_, current_cost)| new_cost < current_cost);
 
             // If we already have a cheaper path to `node`, do nothing.
             if is_empty_or_less_costly {
@@ -6,6 +6,8 @@ use std::{
 pub mod dijkstra;
 pub mod astar;
 
+type ParentMap<Node, Cost> = HashMap<Node, (Option<Node>, Cost)>;
+
 fn build_path<Node, V, F>(target: &Node, parents: &HashMap<Node, V>, mapping: F) -> Vec<Node>
 where
     Node: Eq + Hash + Clone,
@@ -20,12 +22,11 @@ where
     F: Fn(&V) -> Option<&Node>,
 {
     let mut current = target;
-    let reverse_path = std::iter::from_fn(|| {
-        parents.get(current).and_then(&mapping).map(|node| {
+    let reverse_path = std::iter::once(target).chain(std::iter::from_fn(|| {
+        parents.get(current).and_then(&mapping).inspect(|node| {
             current = node;
-            node
         })
-    })
+    }))
     .collect::<Vec<&Node>>();
 
     reverse_path.into_iter().rev().collect()
@@ -0,0 +1,192 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::cartesian::{p2, Cartesian2, Point2, Vector2};
+
+use super::{in_bound, index_to_point, Grid};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Bit packed boolean grid, each row is stored as `words_per_row` little endian words (bit `x % 64` of word `x / 64`)
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    words_per_row: usize,
+    data: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self { width, height, words_per_row, data: vec![0; words_per_row * height] }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width, grid.height);
+        for (index, value) in grid.data.iter().enumerate() {
+            if predicate(value) {
+                bits.set(&grid.index_to_point(index), true);
+            }
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid {
+            data: (0..self.width * self.height).map(|index| self.get(&self.index_to_point(index))).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn same_size(&self) -> Self { Self::new(self.width, self.height) }
+    pub fn index_to_point(&self, index: usize) -> Point2 { index_to_point(index, self.width) }
+    pub fn in_bound(&self, pos: &Point2) -> bool { in_bound(pos, self.width, self.height) }
+
+    fn word_and_mask(&self, pos: &Point2) -> (usize, u64) {
+        assert!(self.in_bound(pos), "{pos:?} is out of bounds {}x{}", self.width, self.height);
+        let x = pos.x() as usize;
+        (pos.y() as usize * self.words_per_row + x / WORD_BITS, 1 << (x % WORD_BITS))
+    }
+
+    pub fn get(&self, pos: &Point2) -> bool {
+        let (word, mask) = self.word_and_mask(pos);
+        self.data[word] & mask != 0
+    }
+
+    pub fn set(&mut self, pos: &Point2, value: bool) {
+        let (word, mask) = self.word_and_mask(pos);
+        if value { self.data[word] |= mask } else { self.data[word] &= !mask }
+    }
+
+    /// Sets the bit and returns whether it was previously unset, like `HashSet::insert`
+    pub fn insert(&mut self, pos: &Point2) -> bool {
+        let (word, mask) = self.word_and_mask(pos);
+        let inserted = self.data[word] & mask == 0;
+        self.data[word] |= mask;
+        inserted
+    }
+
+    pub fn clear(&mut self) { self.data.fill(0) }
+    pub fn count_ones(&self) -> usize { self.data.iter().map(|word| word.count_ones() as usize).sum() }
+
+    pub fn ones(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.data.iter().enumerate().flat_map(move |(index, &word)| {
+            let (y, x_offset) = (index / self.words_per_row, (index % self.words_per_row) * WORD_BITS);
+            (0..WORD_BITS)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| p2((x_offset + bit) as i64, y as i64))
+        })
+    }
+
+    pub fn cardinal_neighbors(&self, pos: &Point2) -> impl Iterator<Item = Point2> + '_ {
+        self.neighbors(pos, &Vector2::ORTHOGONAL)
+    }
+
+    pub fn neighbors<'a>(&'a self, pos: &Point2, directions: &'a [Vector2]) -> impl Iterator<Item = Point2> + 'a {
+        let pos = *pos;
+        directions.iter().map(move |&direction| pos + direction).filter(|neighbor| self.in_bound(neighbor))
+    }
+
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.data[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.data[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// The row ops read missing trailing words of `row` as zero and ignore extra ones,
+    /// bits past `width` are cleared afterwards
+    pub fn and_row(&mut self, y: usize, row: &[u64]) { self.combine_row(y, row, |word, other| word & other) }
+    pub fn or_row(&mut self, y: usize, row: &[u64]) { self.combine_row(y, row, |word, other| word | other) }
+    pub fn xor_row(&mut self, y: usize, row: &[u64]) { self.combine_row(y, row, |word, other| word ^ other) }
+
+    fn combine_row(&mut self, y: usize, row: &[u64], op: impl Fn(u64, u64) -> u64) {
+        let width = self.width;
+        let words = self.row_mut(y);
+        words.iter_mut().enumerate().for_each(|(i, word)| *word = op(*word, row.get(i).copied().unwrap_or(0)));
+        mask_padding(width, words);
+    }
+
+    /// Row `y` with every bit moved from `x` to `x + shift`, bits leaving the grid are dropped
+    pub fn shift_row(&self, y: usize, shift: i64) -> Vec<u64> {
+        let row = self.row(y);
+        let (words, bits) = ((shift.unsigned_abs() as usize) / WORD_BITS, (shift.unsigned_abs() as usize) % WORD_BITS);
+        let word_at = |i: isize| if i >= 0 && (i as usize) < row.len() { row[i as usize] } else { 0 };
+        let mut shifted = (0..row.len() as isize).map(|i| {
+            if shift >= 0 {
+                let (high, low) = (word_at(i - words as isize), word_at(i - words as isize - 1));
+                if bits == 0 { high } else { (high << bits) | (low >> (WORD_BITS - bits)) }
+            } else {
+                let (low, high) = (word_at(i + words as isize), word_at(i + words as isize + 1));
+                if bits == 0 { low } else { (low >> bits) | (high << (WORD_BITS - bits)) }
+            }
+        }).collect::<Vec<_>>();
+        mask_padding(self.width, &mut shifted);
+        shifted
+    }
+
+    /// Whole grid translated by `direction`, cells leaving the grid are dropped
+    pub fn shift(&self, direction: Vector2) -> Self {
+        let mut shifted = self.same_size();
+        for y in 0..self.height {
+            let source = y as i64 - direction.y();
+            if source >= 0 && source < self.height as i64 {
+                let row = self.shift_row(source as usize, direction.x());
+                shifted.row_mut(y).copy_from_slice(&row);
+            }
+        }
+        shifted
+    }
+}
+
+/// Clears the bits past `width` in the last word of `row`
+fn mask_padding(width: usize, row: &mut [u64]) {
+    let used = width % WORD_BITS;
+    if let (Some(last), true) = (row.last_mut(), used != 0) {
+        *last &= (1 << used) - 1;
+    }
+}
+
+impl std::ops::Index<&Point2> for BitGrid {
+    type Output = bool;
+    fn index(&self, pos: &Point2) -> &bool {
+        if self.get(pos) { &true } else { &false }
+    }
+}
+
+macro_rules! bit_grid_ops {
+    ($($trait_name:ident, $method:ident, $op:tt);*) => ($(
+        impl $trait_name for &BitGrid {
+            type Output = BitGrid;
+            fn $method(self, rhs: Self) -> BitGrid {
+                assert_eq!((self.width, self.height), (rhs.width, rhs.height), "grids must have the same size");
+                BitGrid { data: self.data.iter().zip(&rhs.data).map(|(a, b)| a $op b).collect(), ..self.clone() }
+            }
+        }
+    )*)
+}
+
+bit_grid_ops!(BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+    fn not(self) -> BitGrid {
+        let mut negated = BitGrid { data: self.data.iter().map(|word| !word).collect(), ..self.clone() };
+        negated.data.chunks_mut(self.words_per_row.max(1)).for_each(|row| mask_padding(self.width, row));
+        negated
+    }
+}
+
+impl std::fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height as i64 {
+            for x in 0..self.width as i64 {
+                write!(f, "{}", if self.get(&p2(x, y)) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
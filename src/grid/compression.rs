@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+use crate::cartesian::{p2, Cartesian2, Point2};
+
+use super::Grid;
+
+/// Sorted compressed cells along one axis, each covering `[starts[i], starts[i + 1])`.
+/// Every distinct coordinate gets its own unit cell, and the gaps between them are merged into a single cell.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Axis {
+    starts: Vec<i64>,
+}
+
+impl Axis {
+    /// A one unit border is added around the values so the outside of a shape stays connected
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        let (Some(&min), Some(&max)) = (values.first(), values.last()) else {
+            return Self { starts: Vec::new() };
+        };
+        let mut starts = vec![min - 1];
+        for (i, &value) in values.iter().enumerate() {
+            starts.push(value);
+            if values.get(i + 1).is_some_and(|&next| next > value + 1) {
+                starts.push(value + 1);
+            }
+        }
+        starts.extend([max + 1, max + 2]);
+        Self { starts }
+    }
+
+    /// Number of compressed cells
+    pub fn len(&self) -> usize { self.starts.len().saturating_sub(1) }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Compressed cell containing `value`
+    pub fn index(&self, value: i64) -> Option<usize> {
+        match self.starts.binary_search(&value) {
+            Ok(index) => (index < self.len()).then_some(index),
+            Err(0) => None,
+            Err(index) => (index <= self.len()).then_some(index - 1),
+        }
+    }
+
+    /// Real coordinates covered by a compressed cell
+    pub fn range(&self, index: usize) -> Range<i64> { self.starts[index]..self.starts[index + 1] }
+    pub fn length(&self, index: usize) -> i64 { self.starts[index + 1] - self.starts[index] }
+}
+
+/// Maps huge sparse coordinates to a small dense [`Grid`], keeping track of the real area behind each cell
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Compression {
+    pub x_axis: Axis,
+    pub y_axis: Axis,
+}
+
+impl Compression {
+    pub fn new<'a>(points: impl IntoIterator<Item = &'a Point2>) -> Self {
+        let (xs, ys): (Vec<_>, Vec<_>) = points.into_iter().map(|p| (p.x(), p.y())).unzip();
+        Self { x_axis: Axis::new(xs), y_axis: Axis::new(ys) }
+    }
+
+    pub fn width(&self) -> usize { self.x_axis.len() }
+    pub fn height(&self) -> usize { self.y_axis.len() }
+
+    pub fn grid<T: Clone>(&self, value: T) -> Grid<T> {
+        Grid { data: vec![value; self.width() * self.height()], width: self.width(), height: self.height() }
+    }
+
+    pub fn compress(&self, pos: &Point2) -> Option<Point2> {
+        Some(p2(self.x_axis.index(pos.x())? as i64, self.y_axis.index(pos.y())? as i64))
+    }
+
+    /// Real `(x, y)` ranges covered by a compressed cell
+    pub fn decompress(&self, pos: &Point2) -> (Range<i64>, Range<i64>) {
+        (self.x_axis.range(pos.x() as usize), self.y_axis.range(pos.y() as usize))
+    }
+
+    /// Real area covered by a compressed cell
+    pub fn weight(&self, pos: &Point2) -> i64 {
+        self.x_axis.length(pos.x() as usize) * self.y_axis.length(pos.y() as usize)
+    }
+
+    pub fn weights(&self) -> Grid<i64> {
+        let mut weights = self.grid(0);
+        for index in 0..weights.data.len() {
+            weights.data[index] = self.weight(&weights.index_to_point(index));
+        }
+        weights
+    }
+
+    /// Real area of the compressed cells matching `predicate`
+    pub fn area<T>(&self, grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> i64 {
+        grid.data.iter().enumerate()
+            .filter(|(_, value)| predicate(value))
+            .map(|(index, _)| self.weight(&grid.index_to_point(index)))
+            .sum()
+    }
+
+    /// Sets every compressed cell crossed by the axis aligned segment `[from, to]` (in real coordinates)
+    pub fn draw_segment<T: Clone>(&self, grid: &mut Grid<T>, from: &Point2, to: &Point2, value: T) {
+        let (from, to) = (self.compress(from).unwrap(), self.compress(to).unwrap());
+        assert!(from.x() == to.x() || from.y() == to.y(), "only axis aligned segments can be drawn");
+        for y in from.y().min(to.y())..=from.y().max(to.y()) {
+            for x in from.x().min(to.x())..=from.x().max(to.x()) {
+                grid[&p2(x, y)] = value.clone();
+            }
+        }
+    }
+}
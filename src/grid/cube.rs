@@ -0,0 +1,134 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::cartesian::{p2, Cartesian2, Direction, Point2, Vector};
+
+use super::{walker::Walker, Grid};
+
+const DIRECTIONS: [Direction; 4] = [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+/// Orientation of a folded face: its outward normal and the 3D directions of the net `Right` and `Down`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Frame {
+    normal: Vector<3>,
+    right: Vector<3>,
+    down: Vector<3>,
+}
+
+impl Frame {
+    fn axis(&self, direction: Direction) -> Vector<3> {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Left => -self.right,
+            Direction::Down => self.down,
+            Direction::Up => -self.down,
+        }
+    }
+
+    /// Frame of the face reached by folding the net along the edge in `direction`
+    fn fold(&self, direction: Direction) -> Frame {
+        let normal = self.axis(direction);
+        match direction {
+            Direction::Right => Frame { normal, right: -self.normal, ..*self },
+            Direction::Left => Frame { normal, right: self.normal, ..*self },
+            Direction::Down => Frame { normal, down: -self.normal, ..*self },
+            Direction::Up => Frame { normal, down: self.normal, ..*self },
+        }
+    }
+}
+
+/// The six faces of a cube net drawn in a [`Grid`], with the edges glued together once folded
+#[derive(Debug, Clone)]
+pub struct CubeNet {
+    pub size: usize,
+    /// Top left corner of each face in the grid
+    pub faces: [Point2; 6],
+    frames: [Frame; 6],
+}
+
+impl CubeNet {
+    /// Finds the six `size x size` faces of a net made of the non `void` cells.
+    /// The grid must be rectangular (padded with void cells), returns `None` if the cells do not form a cube net.
+    pub fn detect<T>(grid: &Grid<T>, void: impl Fn(&T) -> bool) -> Option<Self> {
+        let cells = grid.data.iter().filter(|cell| !void(cell)).count();
+        let size = (cells / 6).isqrt();
+        if size == 0 || size * size * 6 != cells {
+            return None;
+        }
+
+        let tiles = (0..grid.height / size)
+            .flat_map(|y| (0..grid.width / size).map(move |x| p2((x * size) as i64, (y * size) as i64)))
+            .filter(|corner| !void(&grid[corner]))
+            .collect::<Vec<_>>();
+        if tiles.len() != 6 {
+            return None;
+        }
+
+        let mut frames: [Option<Frame>; 6] = [None; 6];
+        frames[0] = Some(Frame { normal: Vector([0, 0, -1]), right: Vector([1, 0, 0]), down: Vector([0, 1, 0]) });
+        let mut to_visit = VecDeque::from([0]);
+        while let Some(face) = to_visit.pop_front() {
+            let frame = frames[face]?;
+            for direction in DIRECTIONS {
+                let corner = tiles[face] + direction.vector() * size as i64;
+                if let Some(neighbor) = tiles.iter().position(|&tile| tile == corner) {
+                    if frames[neighbor].is_none() {
+                        frames[neighbor] = Some(frame.fold(direction));
+                        to_visit.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        if frames.contains(&None) {
+            return None;
+        }
+        let frames = frames.map(|frame| frame.unwrap());
+        let all_sides_covered = (0..6).all(|i| (0..i).all(|j| frames[i].normal != frames[j].normal));
+        all_sides_covered.then(|| CubeNet { size, faces: tiles.try_into().unwrap(), frames })
+    }
+
+    pub fn face_of(&self, pos: &Point2) -> Option<usize> {
+        let size = self.size as i64;
+        self.faces.iter().position(|corner| {
+            (corner.x()..corner.x() + size).contains(&pos.x()) && (corner.y()..corner.y() + size).contains(&pos.y())
+        })
+    }
+
+    /// Face glued to the edge of `face` in `direction`, along with the direction of travel when entering it
+    pub fn neighbor(&self, face: usize, direction: Direction) -> (usize, Direction) {
+        let frame = self.frames[face];
+        let normal = frame.axis(direction);
+        let neighbor = self.frames.iter().position(|other| other.normal == normal).unwrap();
+        let entering = DIRECTIONS.into_iter().find(|&d| self.frames[neighbor].axis(d) == -frame.normal).unwrap();
+        (neighbor, entering)
+    }
+
+    /// State after stepping forward from a walker about to leave its face, `None` if the step stays on the face
+    pub fn wrap(&self, walker: &Walker) -> Option<Walker> {
+        let face = self.face_of(&walker.pos)?;
+        if self.face_of(&(walker.pos + walker.direction.vector())) == Some(face) {
+            return None;
+        }
+        let (size, frame) = (self.size as i64, self.frames[face]);
+        let local = walker.pos - self.faces[face];
+
+        // Cell centers on the surface of a cube spanning `[-size, size]` on each axis
+        let center = frame.normal * size + frame.right * (2 * local.x() + 1 - size) + frame.down * (2 * local.y() + 1 - size);
+        let across = center + frame.axis(walker.direction) - frame.normal;
+
+        let (neighbor, direction) = self.neighbor(face, walker.direction);
+        let target = self.frames[neighbor];
+        let local = p2((across.dot(&target.right) + size - 1) / 2, (across.dot(&target.down) + size - 1) / 2);
+        Some(Walker::new(self.faces[neighbor] + local.vector(), direction))
+    }
+
+    /// Every face edge crossing, to be used with [`super::walker::Edge::Portals`]
+    pub fn portals(&self) -> HashMap<Walker, Walker> {
+        let size = self.size as i64;
+        self.faces.iter()
+            .flat_map(|&corner| (0..size).flat_map(move |y| (0..size).map(move |x| corner + Vector([x, y]))))
+            .flat_map(|pos| DIRECTIONS.map(|direction| Walker::new(pos, direction)))
+            .filter_map(|walker| self.wrap(&walker).map(|next| (walker, next)))
+            .collect()
+    }
+}
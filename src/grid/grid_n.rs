@@ -0,0 +1,113 @@
+use std::array::from_fn;
+
+use crate::cartesian::{Point, Vector};
+
+use super::Grid;
+
+/// Dense grid over `N` dimensions, stored with the first axis varying fastest (like [`Grid`])
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GridN<T, const N: usize> {
+    pub data: Vec<T>,
+    pub shape: [usize; N],
+}
+
+pub type Grid3<T> = GridN<T, 3>;
+pub type Grid4<T> = GridN<T, 4>;
+
+impl<T: Clone, const N: usize> GridN<T, N> {
+    pub fn new(shape: [usize; N], value: T) -> Self {
+        Self { data: vec![value; shape.iter().product()], shape }
+    }
+
+    /// Copy of the sub-grid of `shape` starting at `min`, panics if it goes out of bounds
+    pub fn slice(&self, min: &Point<N>, shape: [usize; N]) -> Self {
+        let data = (0..shape.iter().product())
+            .map(|index| self[&(*min + index_to_point(index, shape).vector())].clone())
+            .collect();
+        Self { data, shape }
+    }
+}
+
+impl<T, const N: usize> GridN<T, N> {
+    pub fn from_fn(shape: [usize; N], mut f: impl FnMut(Point<N>) -> T) -> Self {
+        let data = (0..shape.iter().product()).map(|index| f(index_to_point(index, shape))).collect();
+        Self { data, shape }
+    }
+
+    pub fn len(&self) -> usize { self.data.len() }
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    pub fn in_bound(&self, pos: &Point<N>) -> bool {
+        pos.iter().zip(self.shape).all(|(&x, size)| x >= 0 && x < size as i64)
+    }
+
+    pub fn point_to_index(&self, pos: &Point<N>) -> usize { point_to_index(pos, self.shape) }
+    pub fn index_to_point(&self, index: usize) -> Point<N> { index_to_point(index, self.shape) }
+
+    pub fn get(&self, pos: &Point<N>) -> Option<&T> {
+        self.in_bound(pos).then(|| &self.data[self.point_to_index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: &Point<N>) -> Option<&mut T> {
+        if !self.in_bound(pos) { return None; }
+        let index = self.point_to_index(pos);
+        Some(&mut self.data[index])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        (0..self.len()).map(|index| self.index_to_point(index))
+    }
+
+    /// In bound neighbors over the `3^N - 1` surrounding cells
+    pub fn neighbors(&self, pos: &Point<N>) -> impl Iterator<Item = Point<N>> + '_ {
+        let pos = *pos;
+        Vector::<N>::all_directions().into_iter()
+            .map(move |direction| pos + direction)
+            .filter(|neighbor| self.in_bound(neighbor))
+    }
+
+    pub fn same_size_with<U: Clone>(&self, value: U) -> GridN<U, N> {
+        GridN { data: vec![value; self.len()], shape: self.shape }
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<&Point<N>> for GridN<T, N> {
+    type Output = T;
+    fn index(&self, pos: &Point<N>) -> &T {
+        assert!(self.in_bound(pos), "{pos:?} is out of bounds {:?}", self.shape);
+        &self.data[self.point_to_index(pos)]
+    }
+}
+
+impl<T, const N: usize> std::ops::IndexMut<&Point<N>> for GridN<T, N> {
+    fn index_mut(&mut self, pos: &Point<N>) -> &mut T {
+        assert!(self.in_bound(pos), "{pos:?} is out of bounds {:?}", self.shape);
+        let index = self.point_to_index(pos);
+        &mut self.data[index]
+    }
+}
+
+impl<T> From<Grid<T>> for GridN<T, 2> {
+    fn from(grid: Grid<T>) -> Self {
+        Self { data: grid.data, shape: [grid.width, grid.height] }
+    }
+}
+
+impl<T> From<GridN<T, 2>> for Grid<T> {
+    fn from(grid: GridN<T, 2>) -> Self {
+        let [width, height] = grid.shape;
+        Self { data: grid.data, width, height }
+    }
+}
+
+pub fn point_to_index<const N: usize>(pos: &Point<N>, shape: [usize; N]) -> usize {
+    pos.iter().zip(shape).rev().fold(0, |index, (&x, size)| index * size + x as usize)
+}
+
+pub fn index_to_point<const N: usize>(mut index: usize, shape: [usize; N]) -> Point<N> {
+    Point(from_fn(|axis| {
+        let x = index % shape[axis];
+        index /= shape[axis];
+        x as i64
+    }))
+}
@@ -1,5 +1,16 @@
 use crate::cartesian::{Point2, p2, Cartesian2};
 
+pub mod bit_grid;
+pub mod compression;
+pub mod cube;
+pub mod grid_n;
+pub mod ray;
+pub mod region;
+pub mod search;
+pub mod sparse;
+pub mod walker;
+pub mod wrapping;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Grid <T> {
     pub data: Vec<T>,
@@ -15,7 +26,7 @@ pub fn parse_grid<T>(input: &str, mapping: impl Fn(char) -> T) -> Grid<T> {
     let width = input.lines().next().unwrap().len();
     let height = input.lines().count();
     let map = input.lines().flat_map(|line| line.chars()).map(mapping).collect::<Vec<_>>();
-    return Grid { data: map, width, height };
+    Grid { data: map, width, height }
 }
 
 impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
@@ -47,6 +58,18 @@ impl<T> std::ops::IndexMut<&Point2> for Grid<T> {
     }
 }
 
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.data.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 impl <T> Grid<T> {
     pub fn index_to_point(&self, index: usize) -> Point2 {
         index_to_point(index, self.width)
@@ -59,11 +82,43 @@ impl <T> Grid<T> {
         Grid {
             width: self.width,
             height: self.height,
-            data: vec![value; (self.width * self.height) as usize],
+            data: vec![value; self.width * self.height],
         }
     }
 }
 
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .map(|pos| self[pos].clone())
+            .collect();
+        Grid { data, width: self.height, height: self.width }
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        let data = self.data.chunks(self.width.max(1)).flat_map(|row| row.iter().rev().cloned()).collect();
+        Grid { data, width: self.width, height: self.height }
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        let data = self.data.chunks(self.width.max(1)).rev().flatten().cloned().collect();
+        Grid { data, width: self.width, height: self.height }
+    }
+
+    /// Clockwise quarter turn
+    pub fn rotate_right(&self) -> Self { self.transpose().flip_horizontal() }
+    /// Counter clockwise quarter turn
+    pub fn rotate_left(&self) -> Self { self.transpose().flip_vertical() }
+
+    /// The 8 rotations and flips of the grid: the 4 clockwise rotations, then the same for the horizontally flipped grid
+    pub fn orientations(&self) -> Vec<Self> {
+        [self.clone(), self.flip_horizontal()].into_iter()
+            .flat_map(|grid| std::iter::successors(Some(grid), |grid| Some(grid.rotate_right())).take(4))
+            .collect()
+    }
+}
+
 pub fn index_to_point(index: usize, width: usize) -> Point2 {
     let width = width as i64;
     let index = index as i64;
@@ -0,0 +1,57 @@
+use crate::cartesian::{Cartesian2, Point2, Vector2};
+use crate::num::integer::gcd;
+
+use super::Grid;
+
+impl<T> Grid<T> {
+    /// Cells visited when stepping from `start` (excluded) by `direction` until leaving the grid
+    pub fn ray(&self, start: &Point2, direction: Vector2) -> impl Iterator<Item = (Point2, &T)> + '_ {
+        assert!(direction != Vector2::origin(), "cannot cast a ray along a null direction");
+        std::iter::successors(Some(*start + direction), move |&pos| Some(pos + direction))
+            .take_while(|pos| self.in_bound(pos))
+            .map(|pos| (pos, &self[&pos]))
+    }
+
+    /// First cell along [`Grid::ray`] for which `stop` holds, `None` if the ray leaves the grid first
+    pub fn walk_until(&self, start: &Point2, direction: Vector2, mut stop: impl FnMut(&T) -> bool) -> Option<(Point2, &T)> {
+        self.ray(start, direction).find(|(_, value)| stop(value))
+    }
+
+    /// Whether no lattice point strictly between `from` and `to` is a blocker
+    pub fn is_visible(&self, from: &Point2, to: &Point2, mut blocker: impl FnMut(&T) -> bool) -> bool {
+        lattice_points(from, to)
+            .filter(|pos| pos != from && pos != to)
+            .all(|pos| !self.in_bound(&pos) || !blocker(&self[&pos]))
+    }
+}
+
+/// Points lying exactly on the segment `[from, to]`, stepping by the gcd reduced direction
+pub fn lattice_points(from: &Point2, to: &Point2) -> impl Iterator<Item = Point2> {
+    let delta = *to - *from;
+    let steps = gcd(delta.x(), delta.y());
+    let step = if steps == 0 { delta } else { delta / steps };
+    let from = *from;
+    (0..=steps).map(move |i| from + step * i)
+}
+
+/// Rasterized segment `[from, to]` using Bresenham's algorithm
+pub fn bresenham(from: &Point2, to: &Point2) -> Vec<Point2> {
+    let (dx, dy) = ((to.x() - from.x()).abs(), -(to.y() - from.y()).abs());
+    let (sx, sy) = ((to.x() - from.x()).signum(), (to.y() - from.y()).signum());
+    let mut error = dx + dy;
+    let mut pos = *from;
+    let mut line = vec![pos];
+    while pos != *to {
+        let double_error = 2 * error;
+        if double_error >= dy {
+            error += dy;
+            *pos.x_ref_mut() += sx;
+        }
+        if double_error <= dx {
+            error += dx;
+            *pos.y_ref_mut() += sy;
+        }
+        line.push(pos);
+    }
+    line
+}
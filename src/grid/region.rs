@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use crate::cartesian::{Cartesian, Point2, Vector2};
+
+use super::Grid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RegionStats {
+    pub area: usize,
+    pub perimeter: usize,
+    /// Number of straight sides, equal to the number of corners
+    pub sides: usize,
+    pub min: Point2,
+    pub max: Point2,
+}
+
+const CLOCKWISE: [Vector2; 4] = [Vector2::UP, Vector2::RIGHT, Vector2::DOWN, Vector2::LEFT];
+
+impl<T> Grid<T> {
+    /// Orthogonally connected cells reachable from `start` through cells matching `predicate`, in BFS order
+    pub fn flood_fill(&self, start: &Point2, mut predicate: impl FnMut(&T) -> bool) -> Vec<Point2> {
+        let mut visited = self.same_size_with(false);
+        let mut filled = Vec::new();
+        if !self.in_bound(start) || !predicate(&self[start]) {
+            return filled;
+        }
+        let mut to_visit = VecDeque::from([*start]);
+        visited[start] = true;
+        while let Some(pos) = to_visit.pop_front() {
+            filled.push(pos);
+            for direction in Vector2::ORTHOGONAL {
+                let neighbor = pos + direction;
+                if self.in_bound(&neighbor) && !visited[&neighbor] && predicate(&self[&neighbor]) {
+                    visited[&neighbor] = true;
+                    to_visit.push_back(neighbor);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Labels orthogonally connected regions, ids are given in reading order starting from 0
+    pub fn label_regions(&self, mut same_region: impl FnMut(&T, &T) -> bool) -> Grid<usize> {
+        let mut labels = self.same_size_with(usize::MAX);
+        let mut next_label = 0;
+        for index in 0..self.data.len() {
+            if labels.data[index] != usize::MAX {
+                continue;
+            }
+            let start = self.index_to_point(index);
+            let mut to_visit = vec![start];
+            labels[&start] = next_label;
+            while let Some(pos) = to_visit.pop() {
+                for direction in Vector2::ORTHOGONAL {
+                    let neighbor = pos + direction;
+                    if self.in_bound(&neighbor) && labels[&neighbor] == usize::MAX && same_region(&self[&pos], &self[&neighbor]) {
+                        labels[&neighbor] = next_label;
+                        to_visit.push(neighbor);
+                    }
+                }
+            }
+            next_label += 1;
+        }
+        labels
+    }
+}
+
+impl Grid<usize> {
+    /// Per region statistics of a grid returned by [`Grid::label_regions`], indexed by region id
+    pub fn region_stats(&self) -> Vec<RegionStats> {
+        let regions_count = self.data.iter().max().map_or(0, |&max| max + 1);
+        let mut stats: Vec<Option<RegionStats>> = vec![None; regions_count];
+        let same = |pos: Point2, label: usize| self.in_bound(&pos) && self[&pos] == label;
+
+        for (index, &label) in self.data.iter().enumerate() {
+            let pos = self.index_to_point(index);
+            let perimeter = CLOCKWISE.iter().filter(|&&direction| !same(pos + direction, label)).count();
+            let corners = (0..4).filter(|&i| {
+                let (a, b) = (CLOCKWISE[i], CLOCKWISE[(i + 1) % 4]);
+                match (same(pos + a, label), same(pos + b, label)) {
+                    (false, false) => true,
+                    (true, true) => !same(pos + a + b, label),
+                    _ => false,
+                }
+            }).count();
+
+            let region = stats[label].get_or_insert(RegionStats { area: 0, perimeter: 0, sides: 0, min: pos, max: pos });
+            region.area += 1;
+            region.perimeter += perimeter;
+            region.sides += corners;
+            region.min = region.min.component_min(&pos);
+            region.max = region.max.component_max(&pos);
+        }
+        stats.into_iter().flatten().collect()
+    }
+}
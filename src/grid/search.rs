@@ -0,0 +1,54 @@
+use crate::cartesian::{p2, Point2, Vector2};
+
+use super::Grid;
+
+impl<T: PartialEq> Grid<T> {
+    /// Every `(start, direction)` along which `sequence` can be read, in any of [`Vector2::ALL_DIRECTIONS`]
+    pub fn find_sequence<'a>(&'a self, sequence: &'a [T]) -> impl Iterator<Item = (Point2, Vector2)> + 'a {
+        (0..self.data.len())
+            .map(|index| self.index_to_point(index))
+            .flat_map(|start| Vector2::ALL_DIRECTIONS.into_iter().map(move |direction| (start, direction)))
+            .filter(|&(start, direction)| self.matches_sequence(start, direction, sequence))
+    }
+
+    pub fn matches_sequence(&self, start: Point2, direction: Vector2, sequence: &[T]) -> bool {
+        sequence.iter().enumerate().all(|(i, value)| {
+            let pos = start + direction * i as i64;
+            self.in_bound(&pos) && self[&pos] == *value
+        })
+    }
+
+    /// Top left positions where `template` matches, `None` cells act as wildcards
+    pub fn find_template(&self, template: &Grid<Option<T>>) -> Vec<Point2> {
+        if template.width > self.width || template.height > self.height {
+            return Vec::new();
+        }
+        (0..=self.height - template.height)
+            .flat_map(|y| (0..=self.width - template.width).map(move |x| p2(x as i64, y as i64)))
+            .filter(|&origin| self.matches_template(origin, template))
+            .collect()
+    }
+
+    /// Same as [`Grid::find_template`] under all rotations and flips of `template`,
+    /// returns the matched positions along with the index of the orientation in [`Grid::orientations`]
+    pub fn find_template_oriented(&self, template: &Grid<Option<T>>) -> Vec<(Point2, usize)>
+    where
+        T: Clone,
+    {
+        template.orientations().iter().enumerate()
+            .flat_map(|(orientation, template)| {
+                self.find_template(template).into_iter().map(move |origin| (origin, orientation))
+            })
+            .collect()
+    }
+
+    pub fn matches_template(&self, origin: Point2, template: &Grid<Option<T>>) -> bool {
+        template.data.iter().enumerate().all(|(index, expected)| {
+            let pos = origin + template.index_to_point(index).vector();
+            match expected {
+                None => true,
+                Some(value) => self.in_bound(&pos) && self[&pos] == *value,
+            }
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::aabb::Aabb;
+use crate::cartesian::{p2, Cartesian2, Point, Point2};
+
+use super::Grid;
+
+/// Unbounded grid storing only the cells that were written, every other cell reads as `default`.
+/// Written cells are kept even when their value equals `default` (including the ones reached through `IndexMut`),
+/// so they count in [`SparseGrid::len`] and show up in [`SparseGrid::iter`].
+/// Bounds grow on insertion but are not shrunk on removal, use [`SparseGrid::recompute_bounds`] for that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T, const N: usize = 2> {
+    data: HashMap<Point<N>, T>,
+    default: T,
+    bounds: Option<Aabb<N>>,
+}
+
+impl<T, const N: usize> SparseGrid<T, N> {
+    pub fn new(default: T) -> Self {
+        Self { data: HashMap::new(), default, bounds: None }
+    }
+
+    pub fn default_value(&self) -> &T { &self.default }
+    pub fn len(&self) -> usize { self.data.len() }
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+    pub fn contains(&self, pos: &Point<N>) -> bool { self.data.contains_key(pos) }
+
+    /// Bounding box of the stored cells, `None` if nothing was ever inserted
+    pub fn bounds(&self) -> Option<Aabb<N>> { self.bounds }
+
+    pub fn get(&self, pos: &Point<N>) -> &T {
+        self.data.get(pos).unwrap_or(&self.default)
+    }
+
+    pub fn insert(&mut self, pos: Point<N>, value: T) -> Option<T> {
+        self.extend_bounds(&pos);
+        self.data.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: &Point<N>) -> Option<T> {
+        self.data.remove(pos)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<N>, &T)> {
+        self.data.iter()
+    }
+
+    pub fn recompute_bounds(&mut self) {
+        self.bounds = Aabb::from_points(self.data.keys().copied());
+    }
+
+    fn extend_bounds(&mut self, pos: &Point<N>) {
+        self.bounds = Some(match self.bounds {
+            None => Aabb::new(*pos, *pos),
+            Some(bounds) => bounds.extend(pos),
+        });
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<&Point<N>> for SparseGrid<T, N> {
+    type Output = T;
+    fn index(&self, pos: &Point<N>) -> &T { self.get(pos) }
+}
+
+impl<T: Clone, const N: usize> std::ops::IndexMut<&Point<N>> for SparseGrid<T, N> {
+    fn index_mut(&mut self, pos: &Point<N>) -> &mut T {
+        self.extend_bounds(pos);
+        self.data.entry(*pos).or_insert_with(|| self.default.clone())
+    }
+}
+
+impl<T, const N: usize> FromIterator<(Point<N>, T)> for SparseGrid<T, N>
+where
+    T: Default,
+{
+    fn from_iter<I: IntoIterator<Item = (Point<N>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new(T::default());
+        iter.into_iter().for_each(|(pos, value)| { grid.insert(pos, value); });
+        grid
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Keeps only the cells of `grid` that differ from `default`
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        for (index, value) in grid.data.iter().enumerate() {
+            if *value != sparse.default {
+                sparse.insert(grid.index_to_point(index), value.clone());
+            }
+        }
+        sparse
+    }
+
+    /// Dense copy of the current bounds, along with the point mapped to the grid `(0, 0)` cell
+    pub fn to_grid(&self) -> (Grid<T>, Point2) {
+        let Some(bounds) = self.bounds else {
+            return (Grid { data: Vec::new(), width: 0, height: 0 }, Point2::origin());
+        };
+        let size = bounds.size();
+        let data = bounds.iter_points().map(|pos| self.get(&pos).clone()).collect();
+        (Grid { data, width: size.x() as usize, height: size.y() as usize }, bounds.min)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(Aabb { min, max }) = self.bounds else { return Ok(()) };
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                write!(f, "{}", self.get(&p2(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cartesian::{p2, Cartesian2, Direction, Point2};
+
+use super::Grid;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Walker {
+    pub pos: Point2,
+    pub direction: Direction,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Turn {
+    Right,
+    Left,
+    Opposite,
+    /// Keep facing the same direction
+    Stay,
+}
+
+impl Turn {
+    pub fn apply(&self, direction: Direction) -> Direction {
+        match self {
+            Turn::Right => direction.turn_right(),
+            Turn::Left => direction.turn_left(),
+            Turn::Opposite => direction.opposite(),
+            Turn::Stay => direction,
+        }
+    }
+}
+
+pub type Portal<'a> = Box<dyn Fn(&Walker) -> Option<Walker> + 'a>;
+
+/// What happens when a step would leave the grid
+pub enum Edge<'a> {
+    Stop,
+    /// Come back from the opposite side of the grid
+    Wrap,
+    /// Maps a state to the state after its next step, checked before moving so portals can also sit inside the grid.
+    /// Leaving the grid from a state without portal stops the walk.
+    Portals(HashMap<Walker, Walker>),
+    Custom(Portal<'a>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Step {
+    Moved,
+    /// The next cell was blocked, the walker only turned
+    Blocked,
+    /// The walker left the grid
+    Exited,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WalkOutcome {
+    /// Every state visited, starting with the initial one
+    pub path: Vec<Walker>,
+    /// Whether the walk stopped because a state was visited twice
+    pub looped: bool,
+}
+
+impl WalkOutcome {
+    pub fn positions(&self) -> HashSet<Point2> { self.path.iter().map(|walker| walker.pos).collect() }
+}
+
+impl Walker {
+    pub fn new(pos: Point2, direction: Direction) -> Self { Self { pos, direction } }
+
+    pub fn turn(&mut self, turn: Turn) { self.direction = turn.apply(self.direction) }
+
+    /// State after one step forward, `None` if it leaves the grid and `edge` does not bring it back
+    pub fn forward<T>(&self, grid: &Grid<T>, edge: &Edge) -> Option<Walker> {
+        if let Edge::Portals(portals) = edge {
+            if let Some(&next) = portals.get(self) {
+                return Some(next);
+            }
+        }
+        let next = Walker { pos: self.pos + self.direction.vector(), ..*self };
+        if grid.in_bound(&next.pos) {
+            return Some(next);
+        }
+        match edge {
+            Edge::Stop => None,
+            Edge::Wrap => Some(Walker {
+                pos: p2(next.pos.x().rem_euclid(grid.width as i64), next.pos.y().rem_euclid(grid.height as i64)),
+                ..next
+            }),
+            Edge::Portals(_) => None,
+            Edge::Custom(portal) => portal(self),
+        }
+    }
+
+    /// Moves forward unless the next cell is `blocked`, in which case `on_blocked` is applied instead.
+    /// Portals leading outside the grid count as exiting it
+    pub fn step<T>(&mut self, grid: &Grid<T>, edge: &Edge, blocked: impl Fn(&T) -> bool, on_blocked: Turn) -> Step {
+        match self.forward(grid, edge) {
+            Some(next) if !grid.in_bound(&next.pos) => Step::Exited,
+            None => Step::Exited,
+            Some(next) if blocked(&grid[&next.pos]) => {
+                self.turn(on_blocked);
+                Step::Blocked
+            }
+            Some(next) => {
+                *self = next;
+                Step::Moved
+            }
+        }
+    }
+
+    /// Moves forward up to `count` cells, stopping at the first blocked cell or when leaving the grid.
+    /// Returns the number of cells moved.
+    pub fn advance<T>(&mut self, grid: &Grid<T>, edge: &Edge, blocked: impl Fn(&T) -> bool, count: usize) -> usize {
+        (0..count).take_while(|_| self.step(grid, edge, &blocked, Turn::Stay) == Step::Moved).count()
+    }
+
+    /// Steps until leaving the grid or coming back to an already visited state
+    pub fn walk<T>(mut self, grid: &Grid<T>, edge: &Edge, blocked: impl Fn(&T) -> bool, on_blocked: Turn) -> WalkOutcome {
+        let mut visited = HashSet::from([self]);
+        let mut path = vec![self];
+        loop {
+            if self.step(grid, edge, &blocked, on_blocked) == Step::Exited {
+                return WalkOutcome { path, looped: false };
+            }
+            if !visited.insert(self) {
+                return WalkOutcome { path, looped: true };
+            }
+            path.push(self);
+        }
+    }
+}
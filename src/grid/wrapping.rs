@@ -0,0 +1,25 @@
+use crate::cartesian::{p2, Cartesian2, Point2};
+
+use super::Grid;
+
+/// Infinite tiling view over a [`Grid`], positions are taken modulo `width`/`height`
+#[derive(Debug, Clone, Copy)]
+pub struct WrappingGrid<'a, T>(pub &'a Grid<T>);
+
+impl<T> WrappingGrid<'_, T> {
+    pub fn wrap(&self, pos: &Point2) -> Point2 {
+        let grid = self.0;
+        p2(pos.x().rem_euclid(grid.width as i64), pos.y().rem_euclid(grid.height as i64))
+    }
+
+    pub fn get(&self, pos: &Point2) -> &T { &self.0[&self.wrap(pos)] }
+}
+
+impl<T> std::ops::Index<&Point2> for WrappingGrid<'_, T> {
+    type Output = T;
+    fn index(&self, pos: &Point2) -> &T { self.get(pos) }
+}
+
+impl<T> Grid<T> {
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> { WrappingGrid(self) }
+}
@@ -0,0 +1,148 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::cartesian::{p2, p3, Cartesian2, Cartesian3, HexDirection, ParseDirectionError, Point2, Point3};
+
+/// Pointy-top hexagon in axial coordinates, the implicit cube coordinate is `s = -q - r`.
+/// Neighbors follow [`HexDirection::vector`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const fn new(q: i64, r: i64) -> Self { Self { q, r } }
+
+    pub fn s(&self) -> i64 { -self.q - self.r }
+
+    pub fn axial(&self) -> Point2 { p2(self.q, self.r) }
+    pub fn from_axial(pos: &Point2) -> Self { Self::new(pos.x(), pos.y()) }
+
+    pub fn cube(&self) -> Point3 { p3(self.q, self.r, self.s()) }
+    pub fn from_cube(pos: &Point3) -> Self {
+        assert_eq!(pos.x() + pos.y() + pos.z(), 0, "{pos:?} is not a valid cube coordinate");
+        Self::new(pos.x(), pos.y())
+    }
+
+    /// "odd-r" offset coordinates: `(column, row)` where odd rows are shoved right by half a hexagon
+    pub fn to_offset(&self) -> Point2 { p2(self.q + (self.r - (self.r & 1)) / 2, self.r) }
+    pub fn from_offset(pos: &Point2) -> Self { Self::new(pos.x() - (pos.y() - (pos.y() & 1)) / 2, pos.y()) }
+
+    pub fn neighbor(&self, direction: HexDirection) -> Self { *self + direction }
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> + '_ {
+        HexDirection::ALL.into_iter().map(|direction| self.neighbor(direction))
+    }
+
+    pub fn distance(&self, other: &Self) -> i64 {
+        let delta = *self - *other;
+        (delta.q.abs() + delta.r.abs() + delta.s().abs()) / 2
+    }
+
+    /// 60 degrees clockwise rotation around the origin
+    pub fn rotate_right(&self) -> Self { Self::new(-self.r, -self.s()) }
+    /// 60 degrees counter clockwise rotation around the origin
+    pub fn rotate_left(&self) -> Self { Self::new(-self.s(), -self.q) }
+
+    /// Rotation by `sixths` clockwise steps around `center`, negative values turn counter clockwise
+    pub fn rotate_around(&self, center: &Self, sixths: i64) -> Self {
+        let relative = (0..sixths.rem_euclid(6)).fold(*self - *center, |hex, _| hex.rotate_right());
+        *center + relative
+    }
+
+    /// Hexagons crossed by the straight line from `self` to `other`, both included
+    pub fn line(&self, other: &Self) -> Vec<Self> {
+        let steps = self.distance(other);
+        // nudge the endpoints so points exactly on an edge are rounded consistently
+        let (q0, r0) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (q1, r1) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+        (0..=steps)
+            .map(|i| {
+                let t = if steps == 0 { 0. } else { i as f64 / steps as f64 };
+                round(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t)
+            })
+            .collect()
+    }
+
+    /// Every hexagon at exactly `radius` from `self`, going clockwise (none for a negative radius)
+    pub fn ring(&self, radius: i64) -> Vec<Self> {
+        match radius {
+            ..0 => return Vec::new(),
+            0 => return vec![*self],
+            _ => {}
+        }
+        let mut hex = *self + Hex::from(HexDirection::W) * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for direction in [HexDirection::NE, HexDirection::E, HexDirection::SE, HexDirection::SW, HexDirection::W, HexDirection::NW] {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += direction;
+            }
+        }
+        ring
+    }
+}
+
+/// Closest hexagon to fractional axial coordinates
+fn round(q: f64, r: f64) -> Hex {
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    Hex::new(rq as i64, rr as i64)
+}
+
+impl From<HexDirection> for Hex {
+    fn from(direction: HexDirection) -> Self { Hex::from_axial(&direction.vector().point()) }
+}
+
+impl Add for Hex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output { Self::new(self.q + rhs.q, self.r + rhs.r) }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output { Self::new(self.q - rhs.q, self.r - rhs.r) }
+}
+
+impl std::ops::Mul<i64> for Hex {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self::Output { Self::new(self.q * rhs, self.r * rhs) }
+}
+
+impl Add<HexDirection> for Hex {
+    type Output = Self;
+    fn add(self, rhs: HexDirection) -> Self::Output { self + Hex::from(rhs) }
+}
+
+impl AddAssign<HexDirection> for Hex {
+    fn add_assign(&mut self, rhs: HexDirection) { *self = *self + rhs }
+}
+
+/// Parses a path of directions without separators such as `esenee` (`,` and whitespace separators are also accepted
+/// between directions)
+pub fn parse_path(path: &str) -> Result<Vec<HexDirection>, ParseDirectionError> {
+    let mut directions = Vec::new();
+    let mut chars = path.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() || c == ',' {
+            continue;
+        }
+        // `n` and `s` only combine with an immediately following `e` or `w`
+        let end = match (c.to_ascii_lowercase(), chars.peek()) {
+            ('n' | 's', Some(&(next, pair))) if matches!(pair.to_ascii_lowercase(), 'e' | 'w') => {
+                chars.next();
+                next + pair.len_utf8()
+            }
+            _ => start + c.len_utf8(),
+        };
+        directions.push(path[start..end].parse()?);
+    }
+    Ok(directions)
+}
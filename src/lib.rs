@@ -1,16 +1,19 @@
 #[cfg(feature = "regex")]
 mod regex;
 
+pub mod aabb;
+pub mod automaton;
 pub mod iterator;
 pub mod parsing;
 pub mod nom_parsing;
 pub mod cartesian;
+pub mod geometry;
 pub mod graphs;
+pub mod hex;
 pub mod num;
+pub mod range_set;
 pub mod grid;
 
-use itertools::Itertools;
-
 pub use aoc_utils_proc_macro::*;
 
 use num::integer::Integer;
@@ -20,13 +23,13 @@ macro_rules! get_input { () => (
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/", env!("CARGO_BIN_NAME"), ".txt"))
 )}
 
+/// Area of the polygon (rounded down), see [`geometry::polygon::Polygon`]
 pub fn shoelace_area(points: &[(i64, i64)]) -> i64 {
-    points.into_iter().circular_tuple_windows().map(|((x1, y1), (x2, y2))| {
-        (x2 * y1) - (x1 * y2)
-    }).sum::<i64>().abs() / 2i64
+    points.iter().map(|&(x, y)| cartesian::p2(x, y)).collect::<geometry::polygon::Polygon>().area()
 }
 
-// Pick's theorem using shoelace formula
+/// Lattice points inside or on the boundary of a polygon from its shoelace area, using Pick's theorem.
+/// Exact for integer areas, prefer [`geometry::polygon::Polygon::lattice_points`] otherwise
 pub fn pick_area<T>(inner_area: T, boundary_points_count: T) -> T 
 where
 T:  Integer
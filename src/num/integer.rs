@@ -10,10 +10,57 @@ pub trait Integer:
     + PartialOrd + Ord + Eq
     + ArithOps + BitOps + ArithAssignOps
     + Zero + One + Two + Ten
+    + MulMod
 {}
 
 empty_trait!(Integer for u8 u16 u32 u64 u128 usize i16 i32 i64 i128);
 
+/// Modular multiplication without intermediate overflow, through `i128`/`u128`
+pub trait MulMod: Sized {
+    /// `self * rhs` modulo `modulus`, in `0..modulus` for a positive modulus
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self;
+}
+
+macro_rules! mul_mod {
+    ($wide:ty: $($t:ty)*) => ($(
+        impl MulMod for $t {
+            fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+                (self as $wide * rhs as $wide).rem_euclid(modulus as $wide) as $t
+            }
+        }
+    )*)
+}
+
+mul_mod!(u128: u8 u16 u32 u64 usize);
+mul_mod!(i128: i16 i32 i64);
+
+// No wider type available, fall back on double and add
+impl MulMod for u128 {
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self { double_and_add(self, rhs, modulus) }
+}
+
+impl MulMod for i128 {
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self { double_and_add(self, rhs, modulus) }
+}
+
+/// `a + b` modulo `modulus` for operands in `0..modulus`, without overflowing
+pub(crate) fn add_mod<T: Integer + Copy>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b { a - (modulus - b) } else { a + b }
+}
+
+fn double_and_add<T: Integer + Copy>(a: T, b: T, modulus: T) -> T {
+    let (mut a, mut b) = (rem_euclid(a, modulus), rem_euclid(b, modulus));
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b % T::TWO == T::ONE {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b /= T::TWO;
+    }
+    product
+}
+
 pub trait Unsigned: Integer {}
 pub trait Signed: Integer + Neg {}
 
@@ -29,4 +76,89 @@ where T: Integer + Copy {
         count += 1;
     }
     count
-}
\ No newline at end of file
+}
+/// Greatest common divisor, always non negative
+pub fn gcd<T>(a: T, b: T) -> T
+where T: Integer + Copy {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    if a < T::ZERO { T::ZERO - a } else { a }
+}
+
+/// Least common multiple, always non negative (zero if any argument is zero)
+pub fn lcm<T>(a: T, b: T) -> T
+where T: Integer + Copy {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    let multiple = a / gcd(a, b) * b;
+    if multiple < T::ZERO { T::ZERO - multiple } else { multiple }
+}
+
+/// Remainder in `0..modulus` for a positive modulus
+pub fn rem_euclid<T>(a: T, modulus: T) -> T
+where T: Integer + Copy {
+    let remainder = a % modulus;
+    if remainder < T::ZERO { remainder + modulus } else { remainder }
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T, T)
+where T: Signed + Copy {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::ZERO {
+        (T::ZERO - old_r, T::ZERO - old_x, T::ZERO - old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x = 1 (mod modulus)`, `None` if `a` and `modulus` are not coprime
+pub fn mod_inverse<T>(a: T, modulus: T) -> Option<T>
+where T: Signed + Copy {
+    let (g, x, _) = extended_gcd(rem_euclid(a, modulus), modulus);
+    (g == T::ONE).then(|| rem_euclid(x, modulus))
+}
+
+/// `base ^ exponent` modulo `modulus` by squaring, for a non negative exponent
+pub fn mod_pow<T>(base: T, exponent: T, modulus: T) -> T
+where T: Integer + Copy {
+    let (mut base, mut exponent) = (rem_euclid(base, modulus), exponent);
+    let mut result = rem_euclid(T::ONE, modulus);
+    while exponent > T::ZERO {
+        if exponent % T::TWO == T::ONE {
+            result = result.mul_mod(base, modulus);
+        }
+        base = base.mul_mod(base, modulus);
+        exponent /= T::TWO;
+    }
+    result
+}
+
+/// Chinese remainder theorem over `(remainder, modulus)` congruences, the moduli don't need to be coprime.
+/// Returns the solution in `0..lcm` along with the lcm of the moduli, `None` if the congruences are incompatible
+pub fn crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where T: Signed + Copy {
+    congruences.iter().try_fold((T::ZERO, T::ONE), |(x, modulus), &(remainder, other)| {
+        let g = gcd(modulus, other);
+        let difference = rem_euclid(remainder - x, other);
+        if difference % g != T::ZERO {
+            return None;
+        }
+        let step = other / g;
+        let lcm = modulus * step;
+        // x + modulus * t satisfies both congruences
+        let t = (difference / g).mul_mod(mod_inverse(modulus / g, step)?, step);
+        Some((rem_euclid(x + modulus.mul_mod(t, lcm), lcm), lcm))
+    })
+}
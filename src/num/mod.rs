@@ -1,6 +1,9 @@
 pub mod integer;
 pub mod constant;
+pub mod number;
+pub mod modular;
 pub mod opts;
+pub mod rational;
 
 #[macro_export]
 macro_rules! empty_trait {
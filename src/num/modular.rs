@@ -0,0 +1,136 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::*,
+};
+
+use super::{
+    constant::{One, Ten, Two, Zero},
+    integer::{add_mod, mod_inverse, mod_pow, rem_euclid, Integer, MulMod, Signed},
+};
+
+/// Integer modulo `M`, always stored in `0..M`
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self { Self(value % M) }
+    pub fn value(&self) -> u64 { self.0 }
+
+    pub fn pow(&self, exponent: u64) -> Self { Self(mod_pow(self.0, exponent, M)) }
+
+    /// `None` if the value is not coprime with `M`
+    pub fn inverse(&self) -> Option<Self> { mod_inverse(self.0 as i128, M as i128).map(|x| Self(x as u64)) }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self { Self::new(value) }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self { Self((value as i128).rem_euclid(M as i128) as u64) }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output { Self(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64) }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output { Self(((self.0 as u128 + M as u128 - rhs.0 as u128) % M as u128) as u64) }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output { Self(self.0.mul_mod(rhs.0, M)) }
+}
+
+/// Multiplication by the inverse, panics if `rhs` is not invertible
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let inverse = rhs.inverse().unwrap_or_else(|| panic!("{rhs:?} is not invertible modulo {M}"));
+        Mul::mul(self, inverse)
+    }
+}
+
+/// Remainder of the representatives in `0..M`
+impl<const M: u64> Rem for ModInt<M> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output { Self(self.0 % rhs.0) }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self::default() - self }
+}
+
+macro_rules! mod_int_assign_ops {
+    ($($trait_name:ident $method:ident $op:tt),*) => ($(
+        impl<const M: u64> $trait_name for ModInt<M> {
+            fn $method(&mut self, rhs: Self) { *self = *self $op rhs }
+        }
+    )*)
+}
+
+mod_int_assign_ops!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /, RemAssign rem_assign %);
+
+impl<const M: u64> Zero for ModInt<M> { const ZERO: Self = Self(0); }
+impl<const M: u64> One for ModInt<M> { const ONE: Self = Self(1 % M); }
+impl<const M: u64> Two for ModInt<M> { const TWO: Self = Self(2 % M); }
+impl<const M: u64> Ten for ModInt<M> { const TEN: Self = Self(10 % M); }
+
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{}", self.0) }
+}
+
+impl<const M: u64> Debug for ModInt<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{} (mod {M})", self.0) }
+}
+
+/// Affine map `x -> a * x + b` modulo `modulus`, such as the position of a card through a shuffle
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct AffineMod<T> {
+    pub a: T,
+    pub b: T,
+    pub modulus: T,
+}
+
+impl<T: Integer + Copy> AffineMod<T> {
+    pub fn new(a: T, b: T, modulus: T) -> Self {
+        Self { a: rem_euclid(a, modulus), b: rem_euclid(b, modulus), modulus }
+    }
+
+    pub fn identity(modulus: T) -> Self { Self::new(T::ONE, T::ZERO, modulus) }
+
+    pub fn apply(&self, x: T) -> T { add_mod(self.a.mul_mod(x, self.modulus), self.b, self.modulus) }
+
+    /// `self` followed by `other`: `x -> other(self(x))`
+    pub fn then(&self, other: &Self) -> Self {
+        assert!(self.modulus == other.modulus, "can't compose maps with different moduli");
+        let a = other.a.mul_mod(self.a, self.modulus);
+        let b = add_mod(other.a.mul_mod(self.b, self.modulus), other.b, self.modulus);
+        Self { a, b, modulus: self.modulus }
+    }
+
+    /// `self` applied `exponent` times, by squaring (`exponent` must be non negative)
+    pub fn pow(&self, exponent: T) -> Self {
+        let (mut result, mut square, mut exponent) = (Self::identity(self.modulus), *self, exponent);
+        while exponent > T::ZERO {
+            if exponent % T::TWO == T::ONE {
+                result = result.then(&square);
+            }
+            square = square.then(&square);
+            exponent /= T::TWO;
+        }
+        result
+    }
+}
+
+impl<T: Signed + Copy> AffineMod<T> {
+    /// `x -> a⁻¹ * (x - b)`, `None` when `a` is not invertible
+    pub fn inverse(&self) -> Option<Self> {
+        let a = mod_inverse(self.a, self.modulus)?;
+        Some(Self::new(a, (T::ZERO - a).mul_mod(self.b, self.modulus), self.modulus))
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fmt::Debug, ops::Neg};
+
+use super::{constant::{One, Ten, Two, Zero}, opts::{ArithAssignOps, ArithOps}};
+
+/// Any primitive integer or float, used as the scalar type of points and vectors
+pub trait Number:
+    Sized + Copy + Debug
+    + PartialOrd + PartialEq
+    + ArithOps + ArithAssignOps
+    + Zero + One + Two + Ten
+{
+    /// Lossy conversion, for float computations such as euclidean distances
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! number {
+    ($($t:ty)*) => ($(
+        impl Number for $t {
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*)
+}
+
+number!(u8 u16 u32 u64 u128 usize i16 i32 i64 i128 f32 f64);
+
+pub trait SignedNumber: Number + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! signed_number {
+    ($($t:ty)*) => ($(
+        impl SignedNumber for $t {
+            fn abs(self) -> Self { <$t>::abs(self) }
+            fn signum(self) -> Self { <$t>::signum(self) }
+        }
+    )*)
+}
+
+signed_number!(i16 i32 i64 i128 f32 f64);
@@ -0,0 +1,143 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::*,
+};
+
+use super::{
+    constant::{One, Ten, Two, Zero},
+    integer::{gcd, Signed},
+    number::{Number, SignedNumber},
+};
+
+/// Exact fraction, always reduced with a positive denominator so that equality is structural
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Rational<T = i64> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Signed + Copy> Rational<T> {
+    /// Panics if `denominator` is zero
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(denominator != T::ZERO, "denominator is zero");
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < T::ZERO { T::ZERO - T::ONE } else { T::ONE };
+        Self { numerator: sign * numerator / divisor, denominator: sign * denominator / divisor }
+    }
+
+    pub fn numerator(&self) -> T { self.numerator }
+    pub fn denominator(&self) -> T { self.denominator }
+
+    pub fn is_integer(&self) -> bool { self.denominator == T::ONE }
+    pub fn to_integer(&self) -> Option<T> { self.is_integer().then_some(self.numerator) }
+
+    pub fn floor(&self) -> T {
+        let quotient = self.numerator / self.denominator;
+        if self.numerator % self.denominator < T::ZERO { quotient - T::ONE } else { quotient }
+    }
+    pub fn ceil(&self) -> T { T::ZERO - (-*self).floor() }
+
+    pub fn recip(&self) -> Self { Self::new(self.denominator, self.numerator) }
+}
+
+impl<T: Signed + Copy> From<T> for Rational<T> {
+    fn from(value: T) -> Self { Self { numerator: value, denominator: T::ONE } }
+}
+
+impl<T: Signed + Copy> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Signed + Copy> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl<T: Signed + Copy> Add for Rational<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let (left, right) = (self.denominator / divisor, rhs.denominator / divisor);
+        Self::new(self.numerator * right + rhs.numerator * left, left * rhs.denominator)
+    }
+}
+
+impl<T: Signed + Copy> Sub for Rational<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+}
+
+impl<T: Signed + Copy> Mul for Rational<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cross reduction first to keep intermediate values small
+        let (a, b) = (gcd(self.numerator, rhs.denominator), gcd(rhs.numerator, self.denominator));
+        Self::new((self.numerator / a) * (rhs.numerator / b), (self.denominator / b) * (rhs.denominator / a))
+    }
+}
+
+impl<T: Signed + Copy> Div for Rational<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output { Mul::mul(self, rhs.recip()) }
+}
+
+/// Truncated remainder, same sign as `self` like the primitive integers
+impl<T: Signed + Copy> Rem for Rational<T> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self / rhs;
+        let truncated = if quotient < Self::ZERO { quotient.ceil() } else { quotient.floor() };
+        self - rhs * Self::from(truncated)
+    }
+}
+
+impl<T: Signed + Copy> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self { numerator: T::ZERO - self.numerator, denominator: self.denominator } }
+}
+
+macro_rules! rational_assign_ops {
+    ($($trait_name:ident $method:ident $op:tt),*) => ($(
+        impl<T: Signed + Copy> $trait_name for Rational<T> {
+            fn $method(&mut self, rhs: Self) { *self = *self $op rhs }
+        }
+    )*)
+}
+
+rational_assign_ops!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /, RemAssign rem_assign %);
+
+impl<T: Zero + One> Zero for Rational<T> { const ZERO: Self = Self { numerator: T::ZERO, denominator: T::ONE }; }
+impl<T: One> One for Rational<T> { const ONE: Self = Self { numerator: T::ONE, denominator: T::ONE }; }
+impl<T: Two + One> Two for Rational<T> { const TWO: Self = Self { numerator: T::TWO, denominator: T::ONE }; }
+impl<T: Ten + One> Ten for Rational<T> { const TEN: Self = Self { numerator: T::TEN, denominator: T::ONE }; }
+
+impl<T: Signed + Number> Number for Rational<T> {
+    fn to_f64(self) -> f64 { self.numerator.to_f64() / self.denominator.to_f64() }
+}
+
+impl<T: Signed + SignedNumber> SignedNumber for Rational<T> {
+    fn abs(self) -> Self { Self { numerator: self.numerator.abs(), denominator: self.denominator } }
+    fn signum(self) -> Self { Self::from(self.numerator.signum()) }
+}
+
+impl<T: Display + One + PartialEq> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == T::ONE {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<T: Debug + One + PartialEq> Debug for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == T::ONE {
+            write!(f, "{:?}", self.numerator)
+        } else {
+            write!(f, "{:?}/{:?}", self.numerator, self.denominator)
+        }
+    }
+}
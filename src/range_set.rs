@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+use crate::num::integer::Integer;
+
+/// Set of integers stored as sorted, disjoint and non adjacent half open ranges
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self { Self { ranges: Vec::new() } }
+}
+
+impl<T: Integer + Copy> RangeSet<T> {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn ranges(&self) -> &[Range<T>] { &self.ranges }
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> { self.ranges.iter() }
+
+    pub fn is_empty(&self) -> bool { self.ranges.is_empty() }
+    /// Number of integers in the set
+    pub fn length(&self) -> T { self.ranges.iter().fold(T::ZERO, |acc, range| acc + (range.end - range.start)) }
+
+    pub fn min(&self) -> Option<T> { self.ranges.first().map(|range| range.start) }
+    pub fn max(&self) -> Option<T> { self.ranges.last().map(|range| range.end - T::ONE) }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges.get(index).is_some_and(|range| range.start <= *value)
+    }
+
+    /// Whether every value of `range` is in the set (always true for an empty range)
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.start >= range.end {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(index).is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        // merge with the overlapping or adjacent ranges
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first >= last {
+            return;
+        }
+        let mut remaining = Vec::with_capacity(2);
+        if self.ranges[first].start < range.start {
+            remaining.push(self.ranges[first].start..range.start);
+        }
+        if range.end < self.ranges[last - 1].end {
+            remaining.push(range.end..self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|range| union.insert(range.clone()));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end { i += 1 } else { j += 1 }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.iter().for_each(|range| difference.remove(range.clone()));
+        difference
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+impl<T: Integer + Copy> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self { Self::from_iter([range]) }
+}
+
+/// Piecewise translation of integers, values outside every rule are left unchanged
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RangeMap<T> {
+    rules: Vec<(T, T, T)>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self { Self { rules: Vec::new() } }
+}
+
+impl<T: Integer + Copy> RangeMap<T> {
+    pub fn new() -> Self { Self::default() }
+
+    /// Maps `source_start..source_start + length` to `destination_start..destination_start + length`,
+    /// the first matching rule wins when rules overlap
+    pub fn add_rule(&mut self, source_start: T, destination_start: T, length: T) {
+        self.rules.push((source_start, destination_start, length));
+    }
+
+    pub fn map(&self, value: &T) -> T {
+        self.rules.iter()
+            .find(|&&(source, _, length)| source <= *value && *value < source + length)
+            .map_or(*value, |&(source, destination, _)| *value - source + destination)
+    }
+
+    /// Image of every value of `set`
+    pub fn map_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut remaining = set.clone();
+        let mut mapped = RangeSet::new();
+        for &(source, destination, length) in &self.rules {
+            let matched = remaining.intersection(&RangeSet::from(source..source + length));
+            for range in matched.iter() {
+                mapped.insert(range.start - source + destination..range.end - source + destination);
+            }
+            remaining = remaining.difference(&matched);
+        }
+        mapped.union(&remaining)
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<(T, T, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T, T)>>(iter: I) -> Self { Self { rules: iter.into_iter().collect() } }
+}
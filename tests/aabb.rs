@@ -0,0 +1,47 @@
+use aoc_utils::aabb::*;
+use aoc_utils::cartesian::*;
+
+#[test]
+fn aabb_from_points() {
+    assert_eq!(Rect::from_points([]), None);
+    let rect = Rect::from_points([p2(3, -1), p2(0, 4), p2(2, 2)]).unwrap();
+    assert_eq!(rect, Aabb { min: p2(0, -1), max: p2(3, 4) });
+    assert_eq!(rect.size(), v2(4, 6));
+    assert_eq!(rect.volume(), 24);
+    assert!(rect.contains(&p2(3, 4)));
+    assert!(!rect.contains(&p2(4, 4)));
+    assert_eq!(rect.expand(1), Some(Aabb::new(p2(4, 5), p2(-1, -2))));
+    assert_eq!(rect.expand(-1), Some(Aabb::new(p2(1, 0), p2(2, 3))));
+    assert_eq!(Rect::new(p2(0, 0), p2(0, 0)).expand(-1), None);
+    assert_eq!(rect.expand(-2), None);
+}
+
+#[test]
+fn aabb_set_operations() {
+    let a = Cuboid::new(p3(0, 0, 0), p3(2, 2, 2));
+    let b = Cuboid::new(p3(1, 1, 1), p3(3, 3, 3));
+    assert_eq!(a.intersection(&b), Some(Cuboid::new(p3(1, 1, 1), p3(2, 2, 2))));
+    assert_eq!(a.union(&b), Cuboid::new(p3(0, 0, 0), p3(3, 3, 3)));
+    assert!(!a.intersects(&Cuboid::new(p3(3, 0, 0), p3(3, 0, 0))));
+    assert!(a.union(&b).contains_box(&a));
+
+    let difference = a.difference(&b);
+    assert_eq!(difference.iter().map(Aabb::volume).sum::<i64>(), 27 - 8);
+    for (i, part) in difference.iter().enumerate() {
+        assert!(a.contains_box(part));
+        assert!(!part.intersects(&b));
+        assert!(difference[i + 1..].iter().all(|other| !part.intersects(other)));
+    }
+    assert_eq!(a.difference(&Cuboid::new(p3(5, 5, 5), p3(6, 6, 6))), vec![a]);
+    assert!(a.difference(&a.expand(1).unwrap()).is_empty());
+}
+
+#[test]
+fn aabb_split_and_iter() {
+    let rect = Rect::new(p2(0, 0), p2(1, 2));
+    assert_eq!(rect.iter_points().collect::<Vec<_>>(), vec![p2(0, 0), p2(1, 0), p2(0, 1), p2(1, 1), p2(0, 2), p2(1, 2)]);
+
+    assert_eq!(rect.split(1, 1), (Some(Rect::new(p2(0, 0), p2(1, 0))), Some(Rect::new(p2(0, 1), p2(1, 2)))));
+    assert_eq!(rect.split(0, 0), (None, Some(rect)));
+    assert_eq!(rect.split(0, 5), (Some(rect), None));
+}
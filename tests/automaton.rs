@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use aoc_utils::automaton::*;
+use aoc_utils::cartesian::*;
+use aoc_utils::grid::parse_grid;
+
+fn life(alive: &bool, neighbors: &[&bool]) -> bool {
+    let count = neighbors.iter().filter(|&&&n| n).count();
+    count == 3 || (*alive && count == 2)
+}
+
+#[test]
+fn blinker() {
+    let grid = parse_grid(".....\n..#..\n..#..\n..#..\n.....", |c| c == '#');
+    let expected = parse_grid(".....\n.....\n.###.\n.....\n.....", |c| c == '#');
+
+    let next = step(&grid, &Vector2::ALL_DIRECTIONS, life);
+    assert_eq!(next, expected);
+
+    let mut parallel = grid.clone();
+    assert!(step_parallel_into(&grid, &mut parallel, &Vector2::ALL_DIRECTIONS, life));
+    assert_eq!(parallel, expected);
+
+    let automaton = Automaton::new(grid.clone(), &Vector2::ALL_DIRECTIONS, life);
+    assert_eq!(automaton.run(1_000_000_001).grid(), &expected);
+}
+
+#[test]
+fn steady_state() {
+    let block = parse_grid("....\n.##.\n.#..\n....", |c| c == '#');
+    let mut automaton = Automaton::new(block, &Vector2::ALL_DIRECTIONS, life);
+    assert_eq!(automaton.run_until_stable(), 1);
+    assert_eq!(automaton.grid().data.iter().filter(|&&c| c).count(), 4);
+}
+
+#[test]
+fn sparse_glider() {
+    let glider: HashSet<Point2> = [p2(1, 0), p2(2, 1), p2(0, 2), p2(1, 2), p2(2, 2)].into_iter().collect();
+    let rule = |alive: bool, count: usize| count == 3 || (alive && count == 2);
+
+    let mut state = glider.clone();
+    for _ in 0..4 {
+        state = step_sparse(&state, &Vector2::ALL_DIRECTIONS, rule);
+    }
+    let moved: HashSet<Point2> = glider.iter().map(|&p| p + v2(1, 1)).collect();
+    assert_eq!(state, moved);
+
+    let cube: HashSet<Point<3>> = [Point([0, 0, 0]), Point([1, 0, 0]), Point([2, 0, 0])].into_iter().collect();
+    let next = run_sparse(cube, &Vector::<3>::all_directions(), |alive, count| count == 2 || (alive && count == 1), 2);
+    assert!(!next.is_empty());
+}
@@ -1,4 +1,5 @@
 use aoc_utils::cartesian::*;
+use aoc_utils::num::constant::Zero;
 
 #[test]
 fn test_point() {
@@ -33,24 +34,47 @@ fn vector_constructor() {
 fn vector_methods() {
     assert_eq!(Vector2::origin(), Vector([0, 0]));
     assert_eq!(Vector2::default(), Vector([0, 0]));
-    assert_eq!(Vector2::zero(), Vector([0, 0]));
+    assert_eq!(Vector2::ZERO, Vector([0, 0]));
     assert_eq!(v2(1,2).point(), Point([1, 2]));
     assert_eq!(v2(4,2).square_magnitude(), 4*4 + 2*2);
 }
 
 #[test]
 fn vector_is_aligned() {
-    assert_eq!(v2(1,2).is_aligned(), false);
-    assert_eq!(v2(1,0).is_aligned(), true);
-    assert_eq!(v2(0,4).is_aligned(), true);
+    assert!(!v2(1,2).is_aligned());
+    assert!(v2(1,0).is_aligned());
+    assert!(v2(0,4).is_aligned());
+    assert!(!v2(0,0).is_aligned());
+    assert!(!v3(1,0,1).is_aligned());
 }
 
 #[test]
 fn vector_normalize() {
-    // TODO: how assert error v2(1,2).normalize()
     assert_eq!(v2(2,0).normalize(), v2(1, 0));
     assert_eq!(v2(0,4).normalize(), v2(0, 1));
     assert_eq!(v2(0,-6).normalize(), v2(0, -1));
+
+    assert_eq!(v2(1,2).try_normalize(), None);
+    assert_eq!(v2(0,0).try_normalize(), None);
+    assert_eq!(v3(0,0,-5).try_normalize(), Some(v3(0, 0, -1)));
+    assert_eq!(v3(0,2,-5).try_normalize(), None);
+}
+
+#[test]
+#[should_panic(expected = "is not aligned")]
+fn vector_normalize_not_aligned() {
+    v2(1,2).normalize();
+}
+
+#[test]
+fn vector_reduce() {
+    assert_eq!(v2(6,-4).reduce(), v2(3, -2));
+    assert_eq!(v2(0,-4).reduce(), v2(0, -1));
+    assert_eq!(v3(4,8,12).reduce(), v3(1, 2, 3));
+    assert_eq!(v2(0,0).reduce(), v2(0, 0));
+
+    assert_eq!(p3(1, 5, 2).direction_to(&p3(4, 5, -7)), v3(1, 0, -1));
+    assert_eq!(p2(3, 3).direction_to(&p2(3, 3)), v2(0, 0));
 }
 
 #[test]
@@ -75,18 +99,133 @@ fn vector_ops() {
 
 #[test]
 fn vector_from_char() {
-    assert_eq!(Vector2::from('U'), Vector2::UP);
-    assert_eq!(Vector2::from('D'), Vector2::DOWN);
-    assert_eq!(Vector2::from('L'), Vector2::LEFT);
-    assert_eq!(Vector2::from('R'), Vector2::RIGHT);
-
-    assert_eq!(Vector2::from('u'), Vector2::UP);
-    assert_eq!(Vector2::from('d'), Vector2::DOWN);
-    assert_eq!(Vector2::from('l'), Vector2::LEFT);
-    assert_eq!(Vector2::from('r'), Vector2::RIGHT);
-
-    assert_eq!(Vector2::from('^'), Vector2::UP);
-    assert_eq!(Vector2::from('v'), Vector2::DOWN);
-    assert_eq!(Vector2::from('<'), Vector2::LEFT);
-    assert_eq!(Vector2::from('>'), Vector2::RIGHT);
-}
\ No newline at end of file
+    assert_eq!(Vector2::try_from('U'), Ok(Vector2::UP));
+    assert_eq!(Vector2::try_from('D'), Ok(Vector2::DOWN));
+    assert_eq!(Vector2::try_from('L'), Ok(Vector2::LEFT));
+    assert_eq!(Vector2::try_from('R'), Ok(Vector2::RIGHT));
+
+    assert_eq!(Vector2::try_from('u'), Ok(Vector2::UP));
+    assert_eq!(Vector2::try_from('d'), Ok(Vector2::DOWN));
+    assert_eq!(Vector2::try_from('l'), Ok(Vector2::LEFT));
+    assert_eq!(Vector2::try_from('r'), Ok(Vector2::RIGHT));
+
+    assert_eq!(Vector2::try_from('^'), Ok(Vector2::UP));
+    assert_eq!(Vector2::try_from('v'), Ok(Vector2::DOWN));
+    assert_eq!(Vector2::try_from('<'), Ok(Vector2::LEFT));
+    assert_eq!(Vector2::try_from('>'), Ok(Vector2::RIGHT));
+
+    assert_eq!(Vector2::try_from('x'), Err(ParseDirectionError("x".to_string())));
+}
+#[test]
+fn point_3d() {
+    let mut a = p3(1, 2, 3);
+    assert_eq!((a.x(), a.y(), a.z()), (1, 2, 3));
+    *a.z_ref_mut() += 1;
+    assert_eq!(a + v3(1, 1, 1), p3(2, 3, 5));
+    assert_eq!(p3(4, 4, 4) - a, v3(3, 2, 0));
+    assert_eq!(a.manhattan_dist(), 7);
+}
+
+#[test]
+fn vector_3d() {
+    assert_eq!(v3(1, 0, 0).cross(&v3(0, 1, 0)), v3(0, 0, 1));
+    assert_eq!(v3(2, 3, 4).cross(&v3(5, 6, 7)), v3(-3, 6, -3));
+    assert_eq!(v3(2, 3, 4).dot(&v3(5, 6, 7)), 56);
+
+    assert_eq!(Vector3::ORTHOGONAL.len(), 6);
+    assert!(Vector3::ORTHOGONAL.iter().all(|v| v.manhattan_dist() == 1));
+    assert_eq!(Vector3::ALL_DIRECTIONS.to_vec(), Vector::<3>::all_directions());
+}
+
+#[test]
+fn rotations_3d() {
+    let rotations = rotation_matrices();
+    let images = rotations.iter().map(|m| v3(1, 2, 3).rotate(m)).collect::<std::collections::HashSet<_>>();
+    assert_eq!(images.len(), 24);
+
+    // every rotation keeps the handedness of the basis
+    for m in &rotations {
+        let (x, y) = (v3(1, 0, 0).rotate(m), v3(0, 1, 0).rotate(m));
+        assert_eq!(x.cross(&y), v3(0, 0, 1).rotate(m));
+    }
+    assert!(rotations.contains(&[[1, 0, 0], [0, 1, 0], [0, 0, 1]]));
+    assert_eq!(p3(1, 0, 0).rotate(&[[0, -1, 0], [1, 0, 0], [0, 0, 1]]), p3(0, 1, 0));
+}
+
+#[test]
+fn generic_scalar() {
+    let a: Point2<i32> = Point([1, 2]);
+    let b: Point2<i32> = Point([4, -2]);
+    assert_eq!(b - a, Vector([3, -4]));
+    assert_eq!((b - a).manhattan_dist(), 7i32);
+    assert_eq!(a.x(), 1i32);
+    assert_eq!(3 * Vector([1i32, 2]), Vector([3, 6]));
+
+    let big: Vector<3, i128> = Vector([i64::MAX as i128, 1, 0]) * 4;
+    assert_eq!(big.x(), i64::MAX as i128 * 4);
+
+    let f: Vector2<f64> = Vector([3.0, 4.0]);
+    assert_eq!(f.square_magnitude(), 25.0);
+    assert_eq!(-f / 2.0, Vector([-1.5, -2.0]));
+    assert_eq!(Point([0.5, 0.5]) + f, Point([3.5, 4.5]));
+    assert_eq!(format!("{:?}", Point([0.5, 1.0])), "p(0.5, 1.0)");
+    assert_eq!(Point2::<f64>::origin(), Point([0.0, 0.0]));
+}
+
+#[test]
+fn distances() {
+    let (a, b) = (p2(1, -2), p2(4, 2));
+    assert_eq!(a.manhattan_dist_to(&b), 7);
+    assert_eq!(a.chebyshev_dist_to(&b), 4);
+    assert_eq!(a.euclidean_dist_to(&b), 5.0);
+    assert_eq!(v2(-3, 2).chebyshev_dist(), 3);
+    assert_eq!(v3(2, 3, 6).euclidean_dist(), 7.0);
+    assert_eq!(v2(1, 2).dot(&v2(3, -4)), -5);
+}
+
+#[test]
+fn component_wise() {
+    assert_eq!(p2(1, 5).component_min(&p2(3, 2)), p2(1, 2));
+    assert_eq!(p2(1, 5).component_max(&p2(3, 2)), p2(3, 5));
+    assert_eq!(p3(-5, 2, 9).component_clamp(&p3(0, 0, 0), &p3(4, 4, 4)), p3(0, 2, 4));
+    assert_eq!(v2(-3, 4).abs(), v2(3, 4));
+    assert_eq!(v3(-3, 0, 7).signum(), v3(-1, 0, 1));
+
+    assert_eq!(v2(2, 3) * v2(4, -1), v2(8, -3));
+    assert_eq!(v2(8, 9) / v2(2, 3), v2(4, 3));
+    let mut v = v2(1, 2);
+    v *= v2(3, 3);
+    v /= v2(1, 2);
+    assert_eq!(v, v2(3, 3));
+}
+
+#[test]
+fn screen_space_convention() {
+    let p = p2(3, 3);
+    assert_eq!(p.up(1), p + Vector2::UP);
+    assert_eq!(p.down(2), p + Vector2::DOWN * 2);
+    assert_eq!(p.left(1), p + Direction::Left.vector());
+    assert_eq!(p.right(1), p + Direction::Right.vector());
+
+    for direction in Direction::ALL {
+        assert_eq!(direction.vector().rotate_right(), direction.turn_right().vector());
+        assert_eq!(direction.vector().rotate_left(), direction.turn_left().vector());
+    }
+
+    let grid = aoc_utils::grid::parse_char_grid("a\nb");
+    assert_eq!(grid[&p2(0, 1).up(1)], 'a');
+}
+
+#[test]
+fn math_space_convention() {
+    let p = p2(0, 0);
+    assert_eq!(p.up_in::<MathSpace>(1), p2(0, 1));
+    assert_eq!(p.down_in::<MathSpace>(1), p2(0, -1));
+    assert_eq!(Direction::Up.vector_in::<MathSpace>(), v2(0, 1));
+    for direction in Direction::ALL {
+        let v = direction.vector_in::<MathSpace>();
+        assert_eq!(v.rotate_right_in::<MathSpace>(), direction.turn_right().vector_in::<MathSpace>());
+        assert_eq!(v.rotate_left_in::<MathSpace>(), direction.turn_left().vector_in::<MathSpace>());
+    }
+    assert_eq!(Direction::Up.vector_in::<ScreenSpace>(), Vector2::UP);
+}
@@ -0,0 +1,62 @@
+use aoc_utils::cartesian::*;
+use aoc_utils::grid::cube::CubeNet;
+use aoc_utils::grid::parse_char_grid;
+use aoc_utils::grid::walker::*;
+
+const MONKEY_MAP: [&str; 12] = [
+    "        ...#    ",
+    "        .#..    ",
+    "        #...    ",
+    "        ....    ",
+    "...#.......#    ",
+    "........#...    ",
+    "..#....#....    ",
+    "..........#.    ",
+    "        ...#....",
+    "        .....#..",
+    "        .#......",
+    "        ......#.",
+];
+
+#[test]
+fn monkey_map_cube_walk() {
+    let grid = parse_char_grid(&MONKEY_MAP.join("\n"));
+    let net = CubeNet::detect(&grid, |&c| c == ' ').unwrap();
+    assert_eq!(net.size, 4);
+    assert_eq!(net.faces, [p2(8, 0), p2(0, 4), p2(4, 4), p2(8, 4), p2(8, 8), p2(12, 8)]);
+
+    let edge = Edge::Portals(net.portals());
+    let mut walker = Walker::new(p2(8, 0), Direction::Right);
+    let path = "10R5L5R10L4R5L5";
+    for (count, turn) in path.split(['R', 'L']).zip(path.chars().filter(|c| c.is_alphabetic()).map(Some).chain([None])) {
+        walker.advance(&grid, &edge, |&c| c == '#', count.parse().unwrap());
+        match turn {
+            Some('R') => walker.turn(Turn::Right),
+            Some(_) => walker.turn(Turn::Left),
+            None => {}
+        }
+    }
+    let facing = [Direction::Right, Direction::Down, Direction::Left, Direction::Up].iter().position(|&d| d == walker.direction).unwrap() as i64;
+    assert_eq!(1000 * (walker.pos.y() + 1) + 4 * (walker.pos.x() + 1) + facing, 5031);
+}
+
+#[test]
+fn cube_net_adjacency() {
+    let cross = parse_char_grid(" # \n###\n # \n # ");
+    let net = CubeNet::detect(&cross, |&c| c == ' ').unwrap();
+    assert_eq!(net.size, 1);
+    for face in 0..6 {
+        for direction in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+            let (neighbor, entering) = net.neighbor(face, direction);
+            assert_ne!(neighbor, face);
+            assert_eq!(net.neighbor(neighbor, entering.opposite()), (face, direction.opposite()));
+        }
+    }
+    // top face (0) is glued to the left face (1) when going left
+    assert_eq!(net.neighbor(0, Direction::Left), (1, Direction::Down));
+    // bottom face (5) is glued to the top face (0) when going down
+    assert_eq!(net.neighbor(5, Direction::Down), (0, Direction::Down));
+
+    assert!(CubeNet::detect(&parse_char_grid("######"), |&c| c == ' ').is_none());
+    assert!(CubeNet::detect(&parse_char_grid("##.\n.##\n.##"), |&c| c == '.').is_none());
+}
@@ -1,4 +1,4 @@
-use aoc_utils::dijkstra::dijkstra;
+use aoc_utils::graphs::dijkstra::dijkstra;
 
 #[test]
 fn test_dijkstra() {
@@ -0,0 +1,77 @@
+use aoc_utils::cartesian::*;
+
+#[test]
+fn direction() {
+    assert_eq!(Direction::try_from('N'), Ok(Direction::Up));
+    assert_eq!(Direction::from_char('>'), Direction::Right);
+    assert_eq!("north".parse(), Ok(Direction::Up));
+    assert_eq!("Left".parse(), Ok(Direction::Left));
+    assert_eq!("D".parse(), Ok(Direction::Down));
+    let error = "sideways".parse::<Direction>().unwrap_err();
+    assert_eq!(error.to_string(), "\"sideways\" is not a valid direction");
+}
+
+#[test]
+#[should_panic(expected = "\"?\" is not a valid direction")]
+fn direction_from_invalid_char() {
+    Direction::from_char('?');
+}
+
+#[test]
+fn direction8() {
+    assert_eq!(Direction8::ALL.len(), 8);
+    assert_eq!(Direction8::ALL.map(|d| d.vector()).iter().collect::<std::collections::HashSet<_>>().len(), 8);
+    assert_eq!(Direction8::NE.opposite(), Direction8::SW);
+    assert_eq!(Direction8::NW.turn_45_right(), Direction8::N);
+    assert_eq!(Direction8::N.turn_45_left(), Direction8::NW);
+    assert_eq!(Direction8::SE.turn_right(), Direction8::SW);
+    assert!(Direction8::SE.is_diagonal());
+    assert_eq!(Direction8::from(Direction::Up).vector(), Direction::Up.vector());
+
+    assert_eq!("NE".parse(), Ok(Direction8::NE));
+    assert_eq!("south-west".parse(), Ok(Direction8::SW));
+    assert_eq!(Direction8::try_from('w'), Ok(Direction8::W));
+    assert!("NEE".parse::<Direction8>().is_err());
+}
+
+#[test]
+fn hex_direction() {
+    assert_eq!(HexDirection::ALL.iter().map(|d| d.vector()).fold(v2(0, 0), |a, b| a + b), v2(0, 0));
+    assert_eq!(HexDirection::NE.opposite(), HexDirection::SW);
+    assert_eq!(HexDirection::NE.turn_right(), HexDirection::E);
+    assert_eq!(HexDirection::E.turn_left(), HexDirection::NE);
+    assert_eq!("se".parse(), Ok(HexDirection::SE));
+    assert_eq!(HexDirection::try_from('e'), Ok(HexDirection::E));
+    assert_eq!(HexDirection::try_from('n'), Err(ParseDirectionError("n".to_string())));
+}
+
+#[test]
+fn direction3() {
+    assert_eq!(Direction3::ALL.iter().map(|d| d.vector()).fold(v3(0, 0, 0), |a, b| a + b), v3(0, 0, 0));
+    assert!(Direction3::ALL.iter().all(|d| d.vector() == -d.opposite().vector()));
+    assert_eq!(Direction3::North.turn_right(), Direction3::East);
+    assert_eq!(Direction3::North.turn_left(), Direction3::West);
+    assert_eq!(Direction3::Up.turn_left(), Direction3::Up);
+    assert_eq!("U".parse(), Ok(Direction3::Up));
+    assert_eq!("down".parse(), Ok(Direction3::Down));
+}
+
+#[test]
+fn moves() {
+    assert_eq!(parse_moves("R4,U3"), Ok(vec![(Direction::Right, 4), (Direction::Up, 3)]));
+    assert_eq!(parse_moves("R 6\nD 5\n"), Ok(vec![(Direction::Right, 6), (Direction::Down, 5)]));
+    assert_eq!(parse_moves("R4,X3"), Err(ParseMoveError::Direction(ParseDirectionError("X".to_string()))));
+    let error = parse_moves("R4,U3.5").unwrap_err();
+    assert!(matches!(&error, ParseMoveError::Distance(s, _) if s == "U3.5"));
+    assert_eq!(error.to_string(), "\"U3.5\" does not have a valid distance: invalid digit found in string");
+    assert!(matches!(parse_moves("R99999999999999999999"), Err(ParseMoveError::Distance(..))));
+
+    assert_eq!(parse_move("U3"), Ok((Direction3::Up, 3)));
+    assert_eq!(parse_move("NE12"), Ok((Direction8::NE, 12)));
+    assert_eq!(parse_move("sw 4"), Ok((HexDirection::SW, 4)));
+    assert!(parse_move::<Direction8>("X3").is_err());
+    assert!(matches!(parse_move::<Direction8>("N"), Err(ParseMoveError::Distance(..))));
+    assert_eq!(parse_move("south-west 3"), Ok((Direction8::SW, 3)));
+    assert_eq!(parse_move("north-east-2"), Ok((Direction8::NE, -2)));
+    assert_eq!(parse_move("U -1"), Ok((Direction::Up, -1)));
+}
@@ -0,0 +1,247 @@
+use aoc_utils::cartesian::{p2, parse_moves, Cartesian2};
+use aoc_utils::geometry::polygon::Polygon;
+
+#[test]
+fn polygon_lagoon() {
+    let moves = parse_moves("R 6\nD 5\nL 2\nD 2\nR 2\nD 2\nL 5\nU 2\nL 1\nU 2\nR 2\nU 3\nL 2\nU 2").unwrap();
+    let polygon = Polygon::from_moves(p2(0, 0), &moves);
+    assert_eq!(polygon.len(), 14);
+    assert_eq!(polygon.boundary_points(), 38);
+    assert_eq!(polygon.area(), 42);
+    assert_eq!(polygon.interior_points(), 24);
+    assert_eq!(polygon.lattice_points(), 62);
+    let tuples: Vec<_> = polygon.vertices.iter().map(|pos| (pos.x(), pos.y())).collect();
+    assert_eq!(aoc_utils::shoelace_area(&tuples), 42);
+    assert_eq!(aoc_utils::pick_area(42, 38), 62);
+    assert_eq!(polygon.perimeter(), 38.);
+    // clockwise on screen
+    assert!(polygon.signed_double_area() > 0);
+}
+
+#[test]
+fn polygon_pick() {
+    let triangle = Polygon::new(vec![p2(0, 0), p2(4, 0), p2(0, 3)]);
+    assert_eq!(triangle.double_area(), 12);
+    assert_eq!(triangle.boundary_points(), 4 + 3 + 1);
+    assert_eq!(triangle.interior_points(), 3);
+    assert!((triangle.perimeter() - 12.).abs() < 1e-9);
+
+    let half = Polygon::new(vec![p2(0, 0), p2(1, 0), p2(0, 1)]);
+    assert_eq!(half.double_area(), 1);
+    assert_eq!(half.area(), 0);
+    assert_eq!(half.interior_points(), 0);
+    assert_eq!(half.lattice_points(), 3);
+}
+
+#[test]
+fn polygon_contains() {
+    // U shape
+    let polygon: Polygon = [p2(0, 0), p2(2, 0), p2(2, 4), p2(4, 4), p2(4, 0), p2(6, 0), p2(6, 6), p2(0, 6)].into_iter().collect();
+    assert!(polygon.strictly_contains(&p2(1, 1)));
+    assert!(polygon.strictly_contains(&p2(5, 5)));
+    assert!(!polygon.contains(&p2(3, 2)));
+    assert!(!polygon.contains(&p2(7, 3)));
+    assert!(!polygon.contains(&p2(-1, 0)));
+    assert!(polygon.on_boundary(&p2(3, 4)));
+    assert!(polygon.contains(&p2(3, 4)));
+    assert!(!polygon.strictly_contains(&p2(3, 4)));
+    assert!(polygon.on_boundary(&p2(0, 3)));
+
+    let reversed = Polygon::new(polygon.vertices.iter().rev().copied().collect());
+    assert_eq!(reversed.signed_double_area(), -polygon.signed_double_area());
+    let inside = (-1..8).flat_map(|y| (-1..8).map(move |x| p2(x, y)));
+    assert!(inside.clone().all(|pos| reversed.contains(&pos) == polygon.contains(&pos)));
+    assert_eq!(inside.filter(|pos| polygon.contains(pos)).count() as i64, polygon.lattice_points());
+}
+
+#[test]
+fn rational_arithmetic() {
+    use aoc_utils::num::rational::Rational;
+
+    let half = Rational::new(2i64, 4);
+    assert_eq!((half.numerator(), half.denominator()), (1, 2));
+    assert_eq!(Rational::new(3i64, -6), -half);
+    assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+    assert_eq!(half - Rational::new(3, 2), Rational::from(-1));
+    assert_eq!(half * Rational::new(4, 3), Rational::new(2, 3));
+    assert_eq!(half / Rational::new(1, 4), Rational::from(2));
+    assert_eq!(Rational::new(7i64, 2) % Rational::from(2), Rational::new(3, 2));
+    assert!(Rational::new(1i64, 3) < half && -half < Rational::new(-1, 3));
+    assert_eq!(Rational::new(-7i64, 2).floor(), -4);
+    assert_eq!(Rational::new(-7i64, 2).ceil(), -3);
+    assert_eq!(Rational::new(6i64, 3).to_integer(), Some(2));
+    assert_eq!(half.to_integer(), None);
+    assert_eq!(format!("{} {:?}", Rational::new(-3i64, 9), Rational::from(4i64)), "-1/3 4");
+}
+
+mod line {
+    use aoc_utils::cartesian::{p2, p3, v2, v3, Point, Point2};
+    use aoc_utils::geometry::line::{Fraction, Intersection, Line, Ray, Segment};
+
+    fn exact(x: i128, y: i128) -> Point2<Fraction> { Point([Fraction::from(x), Fraction::from(y)]) }
+
+    #[test]
+    fn segments() {
+        let a = Segment::new(p2(0, 0), p2(4, 4));
+        assert_eq!(a.intersection(&Segment::new(p2(0, 4), p2(4, 0))), Intersection::Point(exact(2, 2)));
+        assert_eq!(
+            a.intersection(&Segment::new(p2(0, 1), p2(1, 0))).point(),
+            Some(Point([Fraction::new(1, 2), Fraction::new(1, 2)]))
+        );
+        // touching at an endpoint
+        assert_eq!(a.intersection(&Segment::new(p2(4, 4), p2(9, 0))), Intersection::Point(exact(4, 4)));
+        // the lines cross but not the segments
+        assert!(a.intersection(&Segment::new(p2(5, 0), p2(3, 2))).is_none());
+        // parallel
+        assert!(a.intersection(&Segment::new(p2(1, 0), p2(5, 4))).is_none());
+        // colinear
+        assert_eq!(a.intersection(&Segment::new(p2(6, 6), p2(2, 2))), Intersection::Segment(Segment::new(p2(2, 2), p2(4, 4))));
+        assert_eq!(a.intersection(&Segment::new(p2(6, 6), p2(4, 4))), Intersection::Point(exact(4, 4)));
+        assert!(a.intersection(&Segment::new(p2(5, 5), p2(6, 6))).is_none());
+        // degenerate segments
+        assert_eq!(a.intersection(&Segment::new(p2(3, 3), p2(3, 3))), Intersection::Point(exact(3, 3)));
+        assert!(a.intersection(&Segment::new(p2(3, 2), p2(3, 2))).is_none());
+    }
+
+    #[test]
+    fn lines_and_rays() {
+        let line = Line::new(p2(0, 0), v2(2, 1));
+        assert_eq!(line.intersection(&Line::new(p2(10, 0), v2(0, 3))), Intersection::Point(exact(10, 5)));
+        assert!(line.intersection(&Line::new(p2(0, 1), v2(-4, -2))).is_none());
+        assert_eq!(line.intersection(&Line::new(p2(-4, -2), v2(-2, -1))), Intersection::Line(line));
+
+        let ray = Ray::new(p2(0, 0), v2(1, 0));
+        assert!(ray.intersection(&Ray::new(p2(-2, 2), v2(0, 1))).is_none());
+        assert_eq!(ray.intersection(&Ray::new(p2(3, 2), v2(0, -1))), Intersection::Point(exact(3, 0)));
+        assert_eq!(ray.intersection(&Ray::new(p2(3, 0), v2(2, 0))), Intersection::Ray(Ray::new(p2(3, 0), v2(2, 0))));
+        assert_eq!(ray.intersection(&Ray::new(p2(3, 0), v2(-1, 0))), Intersection::Segment(Segment::new(p2(0, 0), p2(3, 0))));
+        assert_eq!(ray.intersection(&Ray::new(p2(0, 0), v2(-1, 0))), Intersection::Point(exact(0, 0)));
+        assert!(ray.intersection(&Ray::new(p2(-1, 0), v2(-1, 0))).is_none());
+    }
+
+    #[test]
+    fn three_dimensions() {
+        let line = Line::new(p3(0, 0, 0), v3(1, 1, 1));
+        let crossing = line.intersection(&Line::new(p3(2, 0, 2), v3(0, 1, 0)));
+        assert_eq!(crossing.point(), Some(Point([2, 2, 2].map(Fraction::from))));
+        // skew lines
+        assert!(line.intersection(&Line::new(p3(2, 0, 3), v3(0, 1, 0))).is_none());
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let a = Segment::new(p2(0, 0), p2(1 << 33, 1 << 33));
+        let b = Segment::new(p2(0, 1 << 33), p2(1 << 33, 0));
+        assert_eq!(a.intersection(&b), Intersection::Point(exact(1 << 32, 1 << 32)));
+
+        let big = 1 << 40;
+        let a = Line::new(p2(-big, -big), v2(2 * big, 2 * big - 1));
+        let b = Line::new(p2(big, -big), v2(-2 * big, 2 * big - 1));
+        assert_eq!(a.intersection(&b).point(), Some(Point([Fraction::from(0), Fraction::new(-1, 2)])));
+
+        let a = Line::new(p3(0, 0, 0), v3(1 << 34, 1 << 34, 1 << 34));
+        let b = Line::new(p3(1 << 35, 0, 1 << 35), v3(0, 1 << 33, 0));
+        assert_eq!(a.intersection(&b).point(), Some(Point([1i128 << 35; 3].map(Fraction::from))));
+    }
+
+    #[test]
+    fn hailstones() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+        let rays = input.lines().map(|line| {
+            let numbers: Vec<i64> = line.split([',', '@']).map(|x| x.trim().parse().unwrap()).collect();
+            Ray::new(p2(numbers[0], numbers[1]), v2(numbers[3], numbers[4]))
+        }).collect::<Vec<_>>();
+        let area = Fraction::from(7)..=Fraction::from(27);
+        let crossings = (0..rays.len())
+            .flat_map(|i| (i + 1..rays.len()).map(move |j| (i, j)))
+            .filter_map(|(i, j)| rays[i].intersection(&rays[j]).point())
+            .filter(|pos| pos.iter().all(|x| area.contains(x)))
+            .count();
+        assert_eq!(crossings, 2);
+    }
+}
+
+#[test]
+fn convex_hull() {
+    let points = [p2(0, 0), p2(2, 1), p2(4, 0), p2(4, 4), p2(2, 2), p2(0, 4), p2(2, 0), p2(1, 3), p2(4, 0)];
+    let hull = Polygon::convex_hull(&points);
+    assert_eq!(hull.vertices, vec![p2(0, 0), p2(4, 0), p2(4, 4), p2(0, 4)]);
+    assert!(hull.signed_double_area() > 0);
+    assert!(points.iter().all(|pos| hull.contains(pos)));
+
+    assert_eq!(Polygon::convex_hull(&[p2(1, 1), p2(1, 1)]).vertices, vec![p2(1, 1)]);
+    assert_eq!(Polygon::convex_hull(&[p2(0, 0), p2(1, 1), p2(2, 2)]).vertices, vec![p2(0, 0), p2(2, 2)]);
+}
+
+mod diamond {
+    use std::collections::HashSet;
+
+    use aoc_utils::cartesian::{p2, Cartesian, Cartesian2};
+    use aoc_utils::geometry::diamond::{from_chebyshev, row_coverage, to_chebyshev, Diamond};
+
+    #[test]
+    fn diamond_shape() {
+        let diamond = Diamond::new(p2(1, 2), 3);
+        let boundary: Vec<_> = diamond.boundary().collect();
+        assert_eq!(boundary.len(), 12);
+        assert_eq!(boundary.iter().collect::<HashSet<_>>().len(), 12);
+        assert!(boundary.iter().all(|pos| pos.manhattan_dist_to(&diamond.center) == 3));
+        assert_eq!(Diamond::new(p2(1, 2), 0).boundary().collect::<Vec<_>>(), vec![p2(1, 2)]);
+
+        assert_eq!(diamond.row(2), Some(-2..5));
+        assert_eq!(diamond.row(4), Some(0..3));
+        assert_eq!(diamond.row(5), Some(1..2));
+        assert_eq!(diamond.row(6), None);
+
+        let square = diamond.to_chebyshev().unwrap();
+        assert_eq!(Diamond::new(p2(1, 2), -1).to_chebyshev(), None);
+        for y in -3..8 {
+            for x in -4..7 {
+                let pos = p2(x, y);
+                assert_eq!(diamond.contains(&pos), square.contains(&to_chebyshev(&pos)));
+                assert_eq!(from_chebyshev(&to_chebyshev(&pos)), Some(pos));
+            }
+        }
+        assert_eq!(from_chebyshev(&p2(1, 0)), None);
+    }
+
+    #[test]
+    fn beacon_exclusion() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+        let readings: Vec<_> = input.lines().map(|line| {
+            let numbers: Vec<i64> = line
+                .split(|c: char| !c.is_ascii_digit() && c != '-')
+                .filter(|x| !x.is_empty())
+                .map(|x| x.parse().unwrap())
+                .collect();
+            (p2(numbers[0], numbers[1]), p2(numbers[2], numbers[3]))
+        }).collect();
+        let diamonds: Vec<_> = readings.iter().map(|(sensor, beacon)| Diamond::reaching(*sensor, beacon)).collect();
+
+        let beacons: HashSet<_> = readings.iter().map(|(_, beacon)| *beacon).filter(|beacon| beacon.y() == 10).collect();
+        assert_eq!(row_coverage(&diamonds, 10).length() - beacons.len() as i64, 26);
+
+        let distress = diamonds.iter()
+            .flat_map(|diamond| Diamond::new(diamond.center, diamond.radius + 1).boundary())
+            .find(|pos| (0..=20).contains(&pos.x()) && (0..=20).contains(&pos.y()) && !diamonds.iter().any(|d| d.contains(pos)))
+            .unwrap();
+        assert_eq!(distress.x() * 4000000 + distress.y(), 56000011);
+    }
+}
@@ -0,0 +1,286 @@
+use aoc_utils::aabb::Aabb;
+use aoc_utils::cartesian::*;
+use aoc_utils::grid::{parse_char_grid, sparse::SparseGrid};
+
+#[test]
+fn sparse_grid_bounds() {
+    let mut grid = SparseGrid::new('.');
+    assert_eq!(grid.bounds(), None);
+    grid.insert(p2(2, -1), '#');
+    grid.insert(p2(-3, 4), '#');
+    assert_eq!(grid.bounds(), Some(Aabb::new(p2(-3, -1), p2(2, 4))));
+    assert_eq!(grid[&p2(2, -1)], '#');
+    assert_eq!(grid[&p2(0, 0)], '.');
+
+    grid.remove(&p2(-3, 4));
+    assert_eq!(grid.bounds(), Some(Aabb::new(p2(-3, -1), p2(2, 4))));
+    grid.recompute_bounds();
+    assert_eq!(grid.bounds(), Some(Aabb::new(p2(2, -1), p2(2, -1))));
+
+    grid[&p2(5, 5)] = '@';
+    assert_eq!(grid.bounds(), Some(Aabb::new(p2(2, -1), p2(5, 5))));
+}
+
+#[test]
+fn sparse_grid_dense_round_trip() {
+    let dense = parse_char_grid("#..\n.#.\n..#");
+    let sparse = SparseGrid::from_grid(&dense, '.');
+    assert_eq!(sparse.len(), 3);
+    assert_eq!(sparse.to_string(), "#..\n.#.\n..#\n");
+
+    let (back, origin) = sparse.to_grid();
+    assert_eq!(back, dense);
+    assert_eq!(origin, p2(0, 0));
+}
+
+#[test]
+fn sparse_grid_3d() {
+    let grid: SparseGrid<bool, 3> = [(Point([1, 2, 3]), true), (Point([-1, 0, 5]), true)].into_iter().collect();
+    assert!(grid[&Point([1, 2, 3])]);
+    assert!(!grid[&Point([0, 0, 0])]);
+    assert_eq!(grid.bounds(), Some(Aabb::new(Point([-1, 0, 3]), Point([1, 2, 5]))));
+}
+
+#[test]
+fn wrapping_grid() {
+    let grid = parse_char_grid("ab\ncd");
+    let wrapping = grid.wrapping();
+    assert_eq!(wrapping[&p2(0, 0)], 'a');
+    assert_eq!(wrapping[&p2(3, 0)], 'b');
+    assert_eq!(wrapping[&p2(-1, -1)], 'd');
+    assert_eq!(wrapping[&p2(-2, 5)], 'c');
+}
+
+#[test]
+fn grid_n_indexing() {
+    use aoc_utils::grid::grid_n::Grid3;
+
+    let mut grid = Grid3::new([2, 3, 4], 0);
+    assert_eq!(grid.len(), 24);
+    assert!(grid.in_bound(&Point([1, 2, 3])));
+    assert!(!grid.in_bound(&Point([2, 0, 0])));
+    assert!(!grid.in_bound(&Point([0, -1, 0])));
+    assert_eq!(grid.get(&Point([0, 3, 0])), None);
+
+    grid[&Point([1, 2, 3])] = 5;
+    assert_eq!(grid.data[23], 5);
+    assert_eq!(grid.index_to_point(23), Point([1, 2, 3]));
+    assert_eq!(grid.point_to_index(&Point([1, 0, 1])), 7);
+}
+
+#[test]
+fn grid_n_neighbors() {
+    use aoc_utils::grid::grid_n::GridN;
+
+    assert_eq!(Vector::<2>::all_directions().len(), 8);
+    assert_eq!(Vector::<3>::all_directions().len(), 26);
+    assert_eq!(Vector::<4>::all_directions().len(), 80);
+
+    let grid = GridN::new([3, 3, 3], ());
+    assert_eq!(grid.neighbors(&Point([1, 1, 1])).count(), 26);
+    assert_eq!(grid.neighbors(&Point([0, 0, 0])).count(), 7);
+}
+
+#[test]
+fn grid_n_slice() {
+    use aoc_utils::grid::grid_n::GridN;
+
+    let grid = GridN::from_fn([4, 4], |p| p.x() * 10 + p.y());
+    let slice = grid.slice(&p2(1, 2), [2, 2]);
+    assert_eq!(slice.data, vec![12, 22, 13, 23]);
+
+    let flat: aoc_utils::grid::Grid<i64> = slice.into();
+    assert_eq!((flat.width, flat.height), (2, 2));
+}
+
+#[test]
+fn flood_fill() {
+    let grid = parse_char_grid("..#\n.##\n#..");
+    let filled = grid.flood_fill(&p2(0, 0), |&c| c == '.');
+    assert_eq!(filled, vec![p2(0, 0), p2(0, 1), p2(1, 0)]);
+    assert!(grid.flood_fill(&p2(2, 0), |&c| c == '.').is_empty());
+}
+
+#[test]
+fn region_stats() {
+    let grid = parse_char_grid("AAAA\nBBCD\nBBCC\nEEEC");
+    let labels = grid.label_regions(|a, b| a == b);
+    assert_eq!(labels.data, vec![0, 0, 0, 0, 1, 1, 2, 3, 1, 1, 2, 2, 4, 4, 4, 2]);
+
+    let stats = labels.region_stats();
+    let summary = stats.iter().map(|r| (r.area, r.perimeter, r.sides)).collect::<Vec<_>>();
+    assert_eq!(summary, vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]);
+    assert_eq!((stats[2].min, stats[2].max), (p2(2, 1), p2(3, 3)));
+
+    let holes = parse_char_grid("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+    let stats = holes.label_regions(|a, b| a == b).region_stats();
+    assert_eq!((stats[0].area, stats[0].perimeter, stats[0].sides), (21, 36, 20));
+}
+
+#[test]
+fn grid_orientations() {
+    let grid = parse_char_grid("ab\ncd\nef");
+    assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+    assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+    assert_eq!(grid.rotate_left().to_string(), "bdf\nace\n");
+    assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+
+    let orientations = grid.orientations();
+    assert_eq!(orientations.len(), 8);
+    assert_eq!(orientations[2].to_string(), "fe\ndc\nba\n");
+    assert_eq!(orientations[4].to_string(), "ba\ndc\nfe\n");
+}
+
+#[test]
+fn find_sequence() {
+    let grid = parse_char_grid("XMAS\nMM..\nA.A.\nS..S");
+    let found = grid.find_sequence(&['X', 'M', 'A', 'S']).collect::<Vec<_>>();
+    assert_eq!(found, vec![(p2(0, 0), v2(0, 1)), (p2(0, 0), v2(1, 0)), (p2(0, 0), v2(1, 1))]);
+    assert_eq!(grid.find_sequence(&['S', 'A', 'M', 'X']).count(), 3);
+}
+
+#[test]
+fn find_template() {
+    use aoc_utils::grid::parse_grid;
+
+    let grid = parse_char_grid("#...\n.#..\n..#.\n.#.#");
+    let template = parse_grid("#?\n?#", |c| (c != '?').then_some(c));
+    assert_eq!(grid.find_template(&template), vec![p2(0, 0), p2(1, 1), p2(2, 2)]);
+
+    let grid = parse_char_grid("....\n.#..\n.##.\n....");
+    let corner = parse_grid("##\n#.", |c| (c != '?').then_some(c));
+    assert!(grid.find_template(&corner).is_empty());
+    assert_eq!(grid.find_template_oriented(&corner), vec![(p2(1, 1), 3), (p2(1, 1), 6)]);
+}
+
+#[test]
+fn ray_casting() {
+    let grid = parse_char_grid("....\n.#..\n....\n...#");
+    let ray = grid.ray(&p2(0, 1), Vector2::RIGHT).map(|(p, &c)| (p, c)).collect::<Vec<_>>();
+    assert_eq!(ray, vec![(p2(1, 1), '#'), (p2(2, 1), '.'), (p2(3, 1), '.')]);
+    assert_eq!(grid.ray(&p2(0, 0), v2(2, 2)).count(), 1);
+
+    assert_eq!(grid.walk_until(&p2(0, 0), v2(1, 1), |&c| c == '#'), Some((p2(1, 1), &'#')));
+    assert_eq!(grid.walk_until(&p2(0, 0), Vector2::DOWN, |&c| c == '#'), None);
+
+    assert!(!grid.is_visible(&p2(0, 0), &p2(3, 3), |&c| c == '#'));
+    assert!(grid.is_visible(&p2(0, 0), &p2(3, 2), |&c| c == '#'));
+}
+
+#[test]
+fn lines() {
+    use aoc_utils::grid::ray::{bresenham, lattice_points};
+
+    assert_eq!(lattice_points(&p2(0, 0), &p2(6, 4)).collect::<Vec<_>>(), vec![p2(0, 0), p2(3, 2), p2(6, 4)]);
+    assert_eq!(lattice_points(&p2(1, 1), &p2(1, 1)).collect::<Vec<_>>(), vec![p2(1, 1)]);
+    assert_eq!(bresenham(&p2(0, 0), &p2(4, 2)), vec![p2(0, 0), p2(1, 1), p2(2, 1), p2(3, 2), p2(4, 2)]);
+    assert_eq!(bresenham(&p2(0, 0), &p2(-2, 2)), vec![p2(0, 0), p2(-1, 1), p2(-2, 2)]);
+}
+
+#[test]
+fn bit_grid() {
+    use aoc_utils::grid::bit_grid::BitGrid;
+
+    let grid = parse_char_grid("#..#\n.##.\n....");
+    let mut bits = BitGrid::from_grid(&grid, |&c| c == '#');
+    assert_eq!(bits.count_ones(), 4);
+    assert!(bits[&p2(3, 0)]);
+    assert!(!bits[&p2(0, 1)]);
+    assert_eq!(bits.to_string(), "#..#\n.##.\n....\n");
+    assert_eq!(bits.ones().collect::<Vec<_>>(), vec![p2(0, 0), p2(3, 0), p2(1, 1), p2(2, 1)]);
+
+    assert!(bits.insert(&p2(0, 2)));
+    assert!(!bits.insert(&p2(0, 2)));
+    bits.set(&p2(0, 2), false);
+    assert_eq!(bits.to_grid(), aoc_utils::grid::parse_grid("#..#\n.##.\n....", |c| c == '#'));
+
+    assert_eq!(bits.cardinal_neighbors(&p2(0, 0)).count(), 2);
+    assert_eq!((!&bits).count_ones(), 8);
+    assert_eq!(bits.shift(Vector2::RIGHT).to_string(), ".#..\n..##\n....\n");
+    assert_eq!(bits.shift(v2(-1, 1)).to_string(), "....\n..#.\n##..\n");
+    assert_eq!((&bits & &bits.shift(Vector2::LEFT)).to_string(), "....\n.#..\n....\n");
+}
+
+#[test]
+fn bit_grid_wide_rows() {
+    use aoc_utils::grid::bit_grid::BitGrid;
+
+    let mut bits = BitGrid::new(130, 2);
+    bits.set(&p2(63, 0), true);
+    bits.set(&p2(129, 1), true);
+    assert_eq!(bits.row(0).len(), 3);
+
+    let shifted = bits.shift(Vector2::RIGHT);
+    assert_eq!(shifted.ones().collect::<Vec<_>>(), vec![p2(64, 0)]);
+    let shifted = bits.shift(v2(-65, 0));
+    assert_eq!(shifted.ones().collect::<Vec<_>>(), vec![p2(64, 1)]);
+    assert_eq!(bits.shift(v2(66, 0)).ones().collect::<Vec<_>>(), vec![p2(129, 0)]);
+
+    bits.or_row(0, &[0b1, 0, 0]);
+    assert!(bits[&p2(0, 0)]);
+    bits.and_row(0, &[0b1, 0, 0]);
+    assert_eq!(bits.count_ones(), 2);
+}
+
+#[test]
+fn bit_grid_row_ops_padding() {
+    use aoc_utils::grid::bit_grid::BitGrid;
+
+    let mut bits = BitGrid::new(10, 1);
+    bits.or_row(0, &[u64::MAX]);
+    assert_eq!(bits.count_ones(), 10);
+    assert_eq!(bits.ones().count(), 10);
+    bits.xor_row(0, &[u64::MAX << 5]);
+    assert_eq!(bits.ones().collect::<Vec<_>>(), (0..5).map(|x| p2(x, 0)).collect::<Vec<_>>());
+
+    // missing words are zeros: `and` clears them, `or` and `xor` leave them untouched
+    let mut wide = BitGrid::new(130, 1);
+    wide.or_row(0, &[u64::MAX; 3]);
+    assert_eq!(wide.count_ones(), 130);
+    wide.xor_row(0, &[u64::MAX]);
+    assert_eq!(wide.count_ones(), 66);
+    wide.and_row(0, &[0, u64::MAX]);
+    assert_eq!(wide.count_ones(), 64);
+    wide.or_row(0, &[]);
+    assert_eq!(wide.count_ones(), 64);
+}
+
+#[test]
+fn compression_axis() {
+    use aoc_utils::grid::compression::Axis;
+
+    let axis = Axis::new([10, 0, 11, 1_000_000]);
+    assert_eq!(axis.len(), 8);
+    assert_eq!(axis.range(0), -1..0);
+    assert_eq!(axis.range(2), 1..10);
+    assert_eq!(axis.index(5), Some(2));
+    assert_eq!(axis.index(11), Some(4));
+    assert_eq!(axis.index(500), Some(5));
+    assert_eq!(axis.index(1_000_001), Some(7));
+    assert_eq!(axis.index(-2), None);
+    assert_eq!(axis.index(1_000_002), None);
+}
+
+#[test]
+fn compressed_lagoon() {
+    use aoc_utils::grid::compression::Compression;
+
+    let instructions = "R 6,D 5,L 2,D 2,R 2,D 2,L 5,U 2,L 1,U 2,R 2,U 3,L 2,U 2";
+    let mut vertices = vec![p2(0, 0)];
+    for instruction in instructions.split(',') {
+        let (direction, length) = instruction.split_once(' ').unwrap();
+        let step = direction.parse::<Direction>().unwrap().vector() * length.parse::<i64>().unwrap();
+        vertices.push(*vertices.last().unwrap() + step);
+    }
+
+    let compression = Compression::new(&vertices);
+    let mut grid = compression.grid('.');
+    for segment in vertices.windows(2) {
+        compression.draw_segment(&mut grid, &segment[0], &segment[1], '#');
+    }
+    let outside = grid.flood_fill(&p2(0, 0), |&c| c == '.');
+    outside.iter().for_each(|pos| grid[pos] = 'o');
+
+    assert_eq!(compression.area(&grid, |&c| c != 'o'), 62);
+    assert_eq!(compression.weights().data.iter().sum::<i64>(), 9 * 12);
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use aoc_utils::cartesian::{p2, HexDirection, ParseDirectionError};
+use aoc_utils::hex::{parse_path, Hex};
+
+#[test]
+fn parse_hex_path() {
+    use HexDirection::*;
+    assert_eq!(parse_path("esenee").unwrap(), vec![E, SE, NE, E]);
+    assert_eq!(parse_path("ne,sw, w").unwrap(), vec![NE, SW, W]);
+    assert!(parse_path("nn").is_err());
+    assert!(parse_path("n").is_err());
+    assert_eq!(parse_path("né"), Err(ParseDirectionError("n".to_string())));
+    assert_eq!(parse_path("n,e"), Err(ParseDirectionError("n".to_string())));
+    assert_eq!(parse_path("sé"), Err(ParseDirectionError("s".to_string())));
+    assert!(parse_path("eé").is_err());
+}
+
+#[test]
+fn hex_paths() {
+    let walk = |path: &str| parse_path(path).unwrap().into_iter().fold(Hex::ORIGIN, |hex, d| hex + d);
+    assert_eq!(walk("nwwswee"), Hex::ORIGIN);
+    assert_eq!(walk("esew"), Hex::ORIGIN.neighbor(HexDirection::SE));
+
+    let input = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+    let mut black = HashSet::new();
+    for line in input.lines() {
+        let tile = walk(line);
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+    assert_eq!(black.len(), 10);
+}
+
+#[test]
+fn hex_coordinates() {
+    let hex = Hex::new(2, -3);
+    assert_eq!(hex.s(), 1);
+    assert_eq!(Hex::from_cube(&hex.cube()), hex);
+    assert_eq!(Hex::from_axial(&hex.axial()), hex);
+    for q in -4..=4 {
+        for r in -4..=4 {
+            let hex = Hex::new(q, r);
+            assert_eq!(Hex::from_offset(&hex.to_offset()), hex);
+        }
+    }
+    // odd rows are shifted right
+    assert_eq!(Hex::from_offset(&p2(0, 1)).neighbor(HexDirection::NW).to_offset(), p2(0, 0));
+    assert_eq!(Hex::from_offset(&p2(0, 1)).neighbor(HexDirection::NE).to_offset(), p2(1, 0));
+    assert_eq!(Hex::from_offset(&p2(0, 2)).neighbor(HexDirection::NW).to_offset(), p2(-1, 1));
+}
+
+#[test]
+fn hex_distance_and_neighbors() {
+    let hex = Hex::new(1, 1);
+    assert_eq!(hex.neighbors().count(), 6);
+    assert!(hex.neighbors().all(|n| n.distance(&hex) == 1));
+    assert_eq!(Hex::ORIGIN.distance(&Hex::new(3, -1)), 3);
+    assert_eq!(Hex::ORIGIN.distance(&Hex::new(-2, -2)), 4);
+    assert!(Hex::ORIGIN.ring(-1).is_empty());
+    for radius in 0..4 {
+        let ring = hex.ring(radius);
+        assert_eq!(ring.len(), if radius == 0 { 1 } else { 6 * radius as usize });
+        assert!(ring.iter().all(|h| h.distance(&hex) == radius));
+        assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+    }
+}
+
+#[test]
+fn hex_rotation() {
+    let e = Hex::from(HexDirection::E);
+    assert_eq!(e.rotate_right(), Hex::from(HexDirection::SE));
+    assert_eq!(e.rotate_left(), Hex::from(HexDirection::NE));
+    for direction in HexDirection::ALL {
+        assert_eq!(Hex::from(direction).rotate_right(), Hex::from(direction.turn_right()));
+        assert_eq!(Hex::from(direction).rotate_left(), Hex::from(direction.turn_left()));
+    }
+    let hex = Hex::new(3, -1);
+    assert_eq!(hex.rotate_around(&Hex::ORIGIN, 6), hex);
+    assert_eq!(hex.rotate_around(&Hex::ORIGIN, -1), hex.rotate_left());
+    let center = Hex::new(1, 1);
+    assert_eq!(hex.rotate_around(&center, 2).distance(&center), hex.distance(&center));
+}
+
+#[test]
+fn hex_line() {
+    let from = Hex::new(-2, 1);
+    let to = Hex::new(3, -2);
+    let line = from.line(&to);
+    assert_eq!(line.len() as i64, from.distance(&to) + 1);
+    assert_eq!(line.first(), Some(&from));
+    assert_eq!(line.last(), Some(&to));
+    assert!(line.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+    assert_eq!(from.line(&from), vec![from]);
+}
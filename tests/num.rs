@@ -0,0 +1,131 @@
+use aoc_utils::num::integer::{crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow, MulMod};
+use aoc_utils::num::modular::ModInt;
+use aoc_utils::num::opts::ArithOps;
+
+#[test]
+fn gcd_lcm() {
+    assert_eq!(gcd(12, -18), 6);
+    assert_eq!(lcm(4u32, 6), 12);
+    assert_eq!(lcm(-4i64, 6), 12);
+    assert_eq!(lcm(0, 6), 0);
+    for (a, b) in [(240i64, 46), (-7, 3), (0, 5), (17, 0)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn modular_arithmetic() {
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(4, 8), None);
+    assert_eq!(mod_pow(4u64, 13, 497), 445);
+    assert_eq!(mod_pow(-2i64, 3, 5), 2);
+    assert_eq!(mod_pow(7, 0, 1), 0);
+
+    let big = 1u64 << 62;
+    assert_eq!(big.mul_mod(big, 1_000_000_007), ((big as u128 * big as u128) % 1_000_000_007) as u64);
+    let huge = 1u128 << 120;
+    // 2^121 = -1 so 2^240 = -2^119
+    assert_eq!(huge.mul_mod(huge, (1 << 121) + 1), (1 << 121) + 1 - (1 << 119));
+    assert_eq!((-3i64).mul_mod(5, 7), 6);
+}
+
+#[test]
+fn chinese_remainder() {
+    let buses = "7,13,x,x,59,x,31,19";
+    let congruences: Vec<(i64, i64)> = buses.split(',').enumerate()
+        .filter_map(|(offset, bus)| bus.parse::<i64>().ok().map(|bus| (-(offset as i64), bus)))
+        .collect();
+    assert_eq!(crt(&congruences), Some((1068781, 7 * 13 * 59 * 31 * 19)));
+
+    // non coprime moduli
+    assert_eq!(crt(&[(2, 6), (4, 8)]), Some((20, 24)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+}
+
+fn sum_of_squares<T: ArithOps + Copy>(values: &[T], zero: T) -> T { values.iter().fold(zero, |acc, &x| acc + x * x) }
+
+#[test]
+fn mod_int() {
+    type M = ModInt<1_000_000_007>;
+    let a = M::new(1_000_000_000);
+    let b = M::from(-1i64);
+    assert_eq!(b.value(), 1_000_000_006);
+    assert_eq!((a + a).value(), 999_999_993);
+    assert_eq!((M::new(3) - M::new(5)).value(), 1_000_000_005);
+    assert_eq!((a * a).value(), 49);
+    assert_eq!(M::new(2).pow(1_000_000_006), M::new(1));
+    assert_eq!(M::new(6) / M::new(3), M::new(2));
+    assert_eq!(M::new(1) / M::new(2) * M::new(2), M::new(1));
+    assert_eq!(-M::new(1), b);
+    assert_eq!(sum_of_squares(&[M::new(3), M::new(4)], M::new(0)), M::new(25));
+
+    let mut c = ModInt::<10>::new(7);
+    c *= ModInt::new(3);
+    assert_eq!(c.value(), 1);
+    assert_eq!(ModInt::<10>::new(4).inverse(), None);
+}
+
+mod shuffle {
+    use aoc_utils::num::modular::AffineMod;
+
+    /// Maps the position of a card before the technique to its position after
+    fn technique(line: &str, size: i64) -> AffineMod<i64> {
+        let argument = || line.rsplit(' ').next().unwrap().parse::<i64>().unwrap();
+        if line == "deal into new stack" {
+            AffineMod::new(-1, -1, size)
+        } else if line.starts_with("cut") {
+            AffineMod::new(1, -argument(), size)
+        } else {
+            AffineMod::new(argument(), 0, size)
+        }
+    }
+
+    fn shuffle(input: &str, size: i64) -> AffineMod<i64> {
+        input.lines().fold(AffineMod::identity(size), |shuffle, line| shuffle.then(&technique(line, size)))
+    }
+
+    fn deck(shuffle: &AffineMod<i64>) -> Vec<i64> {
+        let mut deck = vec![0; shuffle.modulus as usize];
+        (0..shuffle.modulus).for_each(|card| deck[shuffle.apply(card) as usize] = card);
+        deck
+    }
+
+    #[test]
+    fn small_decks() {
+        let first = shuffle("deal with increment 7\ndeal into new stack\ndeal into new stack", 10);
+        assert_eq!(deck(&first), [0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+        let last = shuffle("deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1", 10);
+        assert_eq!(deck(&last), [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+
+        let inverse = last.inverse().unwrap();
+        assert!((0..10).all(|card| inverse.apply(last.apply(card)) == card));
+        assert_eq!(last.then(&inverse), AffineMod::identity(10));
+        assert_eq!(last.pow(3), last.then(&last).then(&last));
+        assert_eq!(AffineMod::new(2, 1, 10).inverse(), None);
+    }
+
+    #[test]
+    fn huge_repetitions() {
+        let size = 119315717514047i64;
+        let repetitions = 101741582076661;
+        let shuffle = AffineMod::new(-7919, 1234567890123, size);
+        let repeated = shuffle.pow(repetitions);
+        let position = repeated.apply(2020);
+        assert_eq!(repeated.inverse().unwrap().apply(position), 2020);
+        assert_eq!(shuffle.inverse().unwrap().pow(repetitions).apply(position), 2020);
+        assert_eq!(repeated.then(&shuffle), shuffle.pow(repetitions + 1));
+    }
+}
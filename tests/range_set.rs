@@ -0,0 +1,85 @@
+use aoc_utils::range_set::{RangeMap, RangeSet};
+
+#[test]
+fn range_set_insert_remove() {
+    let mut set = RangeSet::new();
+    set.insert(5..10);
+    set.insert(0..2);
+    set.insert(12..15);
+    set.insert(10..11);
+    assert_eq!(set.ranges(), &[0..2, 5..11, 12..15]);
+    set.insert(1..13);
+    assert_eq!(set, RangeSet::from(0..15));
+    set.insert(20..20);
+    assert_eq!(set.length(), 15);
+
+    set.remove(3..5);
+    set.remove(14..30);
+    set.remove(-5..1);
+    assert_eq!(set.ranges(), &[1..3, 5..14]);
+    assert_eq!(set.length(), 11);
+    assert_eq!((set.min(), set.max()), (Some(1), Some(13)));
+
+    assert!(set.contains(&1) && set.contains(&13) && set.contains(&5));
+    assert!(!set.contains(&0) && !set.contains(&3) && !set.contains(&14));
+    assert!(set.contains_range(&(6..10)) && set.contains_range(&(5..14)));
+    assert!(!set.contains_range(&(2..6)));
+
+    set.remove(0..100);
+    assert!(set.is_empty());
+    assert_eq!(set.length(), 0);
+}
+
+#[test]
+fn range_set_operations() {
+    let a: RangeSet<u32> = [0..10, 20..30].into_iter().collect();
+    let b: RangeSet<u32> = [5..25, 28..40].into_iter().collect();
+    assert_eq!(a.union(&b), RangeSet::from(0..40));
+    assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+    assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+    assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+    assert!(a.intersection(&RangeSet::from(10..20)).is_empty());
+}
+
+#[test]
+fn range_map_seeds() {
+    let maps = "50 98 2
+52 50 48
+
+0 15 37
+37 52 2
+39 0 15
+
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+88 18 7
+18 25 70
+
+45 77 23
+81 45 19
+68 64 13
+
+0 69 1
+1 0 69
+
+60 56 37
+56 93 4";
+    let maps: Vec<RangeMap<i64>> = maps.split("\n\n").map(|block| {
+        block.lines().map(|line| {
+            let numbers: Vec<i64> = line.split(' ').map(|x| x.parse().unwrap()).collect();
+            (numbers[1], numbers[0], numbers[2])
+        }).collect()
+    }).collect();
+    let seeds = [79, 14, 55, 13];
+
+    let locations = seeds.map(|seed| maps.iter().fold(seed, |value, map| map.map(&value)));
+    assert_eq!(locations, [82, 43, 86, 35]);
+
+    let seeds: RangeSet<i64> = seeds.chunks(2).map(|pair| pair[0]..pair[0] + pair[1]).collect();
+    let locations = maps.iter().fold(seeds.clone(), |set, map| map.map_set(&set));
+    assert_eq!(locations.length(), seeds.length());
+    assert_eq!(locations.min(), Some(46));
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use aoc_utils::cartesian::*;
+use aoc_utils::grid::parse_char_grid;
+use aoc_utils::grid::walker::*;
+
+const PATROL: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[test]
+fn guard_patrol() {
+    let grid = parse_char_grid(PATROL);
+    let start = grid.index_to_point(grid.data.iter().position(|&c| c == '^').unwrap());
+    let guard = Walker::new(start, Direction::Up);
+
+    let outcome = guard.walk(&grid, &Edge::Stop, |&c| c == '#', Turn::Right);
+    assert!(!outcome.looped);
+    assert_eq!(outcome.positions().len(), 41);
+
+    let mut blocked = grid.clone();
+    blocked[&p2(3, 6)] = '#';
+    let outcome = guard.walk(&blocked, &Edge::Stop, |&c| c == '#', Turn::Right);
+    assert!(outcome.looped);
+}
+
+#[test]
+fn edges() {
+    let grid = parse_char_grid("...\n.#.\n...");
+    let mut walker = Walker::new(p2(0, 0), Direction::Left);
+    assert_eq!(walker.forward(&grid, &Edge::Stop), None);
+    assert_eq!(walker.forward(&grid, &Edge::Wrap), Some(Walker::new(p2(2, 0), Direction::Left)));
+
+    let portals = Edge::Portals(HashMap::from([(walker, Walker::new(p2(0, 2), Direction::Up))]));
+    assert_eq!(walker.step(&grid, &portals, |&c| c == '#', Turn::Right), Step::Moved);
+    assert_eq!(walker, Walker::new(p2(0, 2), Direction::Up));
+
+    let custom = Edge::Custom(Box::new(|w: &Walker| Some(Walker { direction: w.direction.opposite(), ..*w })));
+    walker.turn(Turn::Right);
+    assert_eq!(walker.advance(&grid, &custom, |&c| c == '#', 10), 10);
+    assert_eq!(walker, Walker::new(p2(1, 2), Direction::Left));
+
+    let mut walker = Walker::new(p2(1, 0), Direction::Down);
+    assert_eq!(walker.advance(&grid, &Edge::Wrap, |&c| c == '#', 5), 0);
+    assert_eq!(walker.step(&grid, &Edge::Wrap, |&c| c == '#', Turn::Opposite), Step::Blocked);
+    assert_eq!(walker.direction, Direction::Up);
+
+    // portals leading outside the grid exit instead of panicking
+    let mut walker = Walker::new(p2(0, 0), Direction::Left);
+    let outside = Edge::Portals(HashMap::from([(walker, Walker::new(p2(-3, 1), Direction::Left))]));
+    assert_eq!(walker.step(&grid, &outside, |&c| c == '#', Turn::Right), Step::Exited);
+    let custom = Edge::Custom(Box::new(|w: &Walker| Some(Walker { pos: p2(5, 5), ..*w })));
+    assert_eq!(walker.step(&grid, &custom, |&c| c == '#', Turn::Right), Step::Exited);
+    assert_eq!(walker, Walker::new(p2(0, 0), Direction::Left));
+}